@@ -0,0 +1,98 @@
+// AoC 2023 -- Day 01
+
+// Read a file and sum the two-digit numbers
+// formed by combining the first and last digit
+// of each line, which may be the same character.
+
+use regex::Regex;
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Answer, AocError, Result, Solution};
+use log::trace;
+
+pub struct Day01;
+
+/// the first and last digit of one line, which may be the same character
+#[derive(Debug, Clone, Copy)]
+pub struct Calibration {
+    /// 1-based line number
+    pub line: usize,
+    /// counting only numerals; None if the line has none
+    pub numerals: Option<(i32, i32)>,
+    /// counting spelled-out digits too
+    pub spelled: (i32, i32),
+}
+
+fn parse_elf_digit(elf_digit : &str) -> Result<i32, std::num::ParseIntError> {
+    Ok(match elf_digit {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => elf_digit.parse()?
+    })
+}
+
+/// regexes matching the first and the last `digit` in a line. They are
+/// separate so that the last digit may overlap the first, as in "twone".
+fn first_and_last_regexes(digit: &str) -> Result<(Regex, Regex)> {
+    let first = Regex::new(digit).map_err(|e| e.to_string())?;
+    let last = Regex::new(&format!(r"^.*{}", digit)).map_err(|e| e.to_string())?;
+    Ok((first, last))
+}
+
+/// the first and last digit in the line, if it has any
+fn first_and_last(line: &Line, (first_re, last_re): &(Regex, Regex)) -> Result<Option<(i32, i32)>> {
+    let (Some(first_digit), Some(caps)) = (first_re.find(line.text), last_re.captures(line.text)) else {
+        return Ok(None);
+    };
+    let second_digit = caps.get(1).unwrap();
+    let first = parse_elf_digit(first_digit.as_str()).map_err(|e| line.error_at(first_digit.as_str(), e.to_string()))?;
+    let second = parse_elf_digit(second_digit.as_str()).map_err(|e| line.error_at(second_digit.as_str(), e.to_string()))?;
+    trace!("{} + {} = {}", first_digit.as_str(), second_digit.as_str(), first * 10 + second);
+    Ok(Some((first, second)))
+}
+
+fn sum(pairs: impl Iterator<Item = (i32, i32)>) -> i32 {
+    pairs.map(|(first, second)| first * 10 + second).sum()
+}
+
+impl Solution for Day01 {
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Vec<Calibration>> {
+        const RE_NUMERAL : &str = r"(\d)";
+        const RE_DIGIT : &str = r"(one|two|three|four|five|six|seven|eight|nine|\d)";
+        let re_numeral = first_and_last_regexes(RE_NUMERAL)?;
+        let re_digit = first_and_last_regexes(RE_DIGIT)?;
+        lines(input).map(|line| {
+            let numerals = first_and_last(&line, &re_numeral)?;
+            let spelled = first_and_last(&line, &re_digit)?.ok_or_else(|| line.error(1, "first digit not found"))?;
+            Ok(Calibration { line: line.number, numerals, spelled })
+        }).collect()
+    }
+
+    fn part1(calibrations: &Vec<Calibration>) -> Result<Answer> {
+        let numerals = calibrations.iter()
+            .map(|c| c.numerals.ok_or_else(|| AocError::parse(c.line, 1, "first digit not found")))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(sum(numerals.into_iter()).into())
+    }
+
+    fn part2(calibrations: &Vec<Calibration>) -> Result<Answer> {
+        Ok(sum(calibrations.iter().map(|c| c.spelled)).into())
+    }
+}
+
+#[test]
+fn test_lines_without_digits_are_rejected() {
+    let err = Day01::parse("1abc2\nabc\n").err().unwrap();
+    assert_eq!(err.to_string(), "2:1: first digit not found");
+    let calibrations = Day01::parse("two1nine\nxtwone\n").unwrap();
+    assert_eq!(Day01::part2(&calibrations).unwrap(), 50.into());
+    assert_eq!(Day01::part1(&calibrations).err().unwrap().to_string(), "2:1: first digit not found");
+}
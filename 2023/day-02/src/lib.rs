@@ -0,0 +1,121 @@
+/*
+Example Input: 
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+*/
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    const ALL: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+
+    fn parse(line: &Line, word: &str) -> Result<Color> {
+        match word {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(line.error_at(word, format!("invalid color '{}'", word))),
+        }
+    }
+}
+
+pub struct Game {
+    id: i32,
+    sets: Vec<GameSet>,
+}
+
+struct GameSet {
+    items: Vec<(i32, Color)>,
+}
+
+impl Game {
+    fn parse(line: &Line) -> Result<Game> {
+        let (header, sets_str) = line.text.split_once(':')
+            .ok_or_else(|| line.error_at_end("expected ':'"))?;
+        let game_id: i32 = line.number(line.expect_prefix(header, "Game ")?.trim())?;
+
+        let sets = sets_str.split(';')
+            .map(|set_str| GameSet::parse(line, set_str))
+            .collect::<Result<_>>()?;
+
+        Ok(Game { id: game_id, sets })
+    }
+
+    fn is_possible(&self) -> bool {
+        self.sets.iter().all(GameSet::is_possible)
+    }
+
+    fn power(&self) -> i32 {
+        Color::ALL.iter().map(|color| 
+            self.sets.iter().flat_map(|set| set.iter())
+            .filter(|(_, c)| c == color).map(|(n, _)| *n).max()
+        ).product::<Option<i32>>().unwrap_or(0)
+    }
+}
+
+impl GameSet {
+    fn parse(line: &Line, set_str: &str) -> Result<GameSet> {
+        let items = set_str.split(',')
+            .map(|num_color| {
+                let mut parts = num_color.split_whitespace();
+                let num: i32 = line.number(line.expect(parts.next(), "count")?)?;
+                let color = Color::parse(line, line.expect(parts.next(), "color")?)?;
+                Ok((num, color))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(GameSet { items })
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, (i32, Color)> {
+        self.items.iter()
+    }
+
+    fn is_possible(&self) -> bool {
+        self.items.iter().all(|&(num, color)| check_possible(num, color))
+    }
+}
+
+fn check_possible(num: i32, color: Color) -> bool {
+    match color {
+        Color::Red => num <= 12,
+        Color::Green => num <= 13,
+        Color::Blue => num <= 14,
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        lines(input).map(|line| Game::parse(&line)).collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<Answer> {
+        let sum: i32 = games.iter()
+            .filter(|game| game.is_possible())
+            .map(|game| game.id)
+            .sum();
+        Ok(sum.into())
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<Answer> {
+        let power: i32 = games.iter()
+            .map(|game| game.power())
+            .sum();
+        Ok(power.into())
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Answer, Result, Solution};
+use log::debug;
+use regex::Regex;
+use std::collections::{HashSet, HashMap};
+
+#[derive(Debug)]
+struct PartNumber {
+    number: u32,
+    at: Coord,
+    length: usize,
+}
+
+impl PartNumber {
+    /// return all the neighbors of this number
+    fn neighbors(&self, grid: &Grid<char>) -> HashSet<Coord> {
+        let digits = self.at.col..self.at.col + self.length;
+        digits.clone()
+            .flat_map(|col| grid.neighbors8(Coord::new(self.at.row, col)))
+            .filter(|c| c.row != self.at.row || !digits.contains(&c.col))
+            .collect()
+    }
+}
+
+/// any symbol other than a digit or '.' is a part
+fn is_part(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+pub struct PartsGrid {
+    grid: Grid<char>,
+    numbers: Vec<PartNumber>,
+}
+
+impl PartsGrid {
+    fn add_line_numbers(&mut self, line: &Line) -> Result<()> {
+        // find all numbers in the line
+        let re = Regex::new(r"\d+").map_err(|e| e.to_string())?;
+        for cap0 in re.find_iter(line.text) {
+            let length = cap0.as_str().len();
+            let at = Coord::new(line.number - 1, cap0.start());
+            let number = line.number(cap0.as_str())?;
+            self.numbers.push(PartNumber { number, at, length });
+        }
+        Ok(())
+    }
+
+    fn _print(&self) {
+        debug!("\n{}", self.grid.map(|&c| if is_part(c) { '#' } else { '.' }));
+    }
+
+    fn sum_true_parts(&self) -> u32 {
+        self.numbers.iter()
+            .filter(|n| n.neighbors(&self.grid).iter().any(|&c| is_part(self.grid[c])))
+            .map(|n| n.number)
+            .sum()
+    }
+
+    fn sum_gear_ratios(&self) -> u32 {
+        // get map from parts positions to the numbers neighboring them
+        let mut part_numbers : HashMap<Coord, Vec<u32>> = HashMap::new();
+        for n in &self.numbers {
+            for c in n.neighbors(&self.grid) {
+                if is_part(self.grid[c]) {
+                    part_numbers.entry(c).or_default().push(n.number);
+                }
+            }
+        }
+        // sum the gear ratios
+        part_numbers.iter()
+            .filter(|(&c, _numbers)| self.grid[c] == '*')
+            .filter(|(_part, numbers)| numbers.len() == 2)
+            .map(|(_part, numbers)| numbers[0] * numbers[1])
+            .sum()
+    }
+
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = PartsGrid;
+
+    fn parse(input: &str) -> Result<PartsGrid> {
+        // columns are byte offsets, so the schematic must be ASCII
+        let grid = Grid::parse(input, |c| c.is_ascii().then_some(c))?;
+        let mut parts = PartsGrid { grid, numbers: Vec::new() };
+        for line in lines(input) {
+            parts.add_line_numbers(&line)?;
+        }
+        Ok(parts)
+    }
+
+    fn part1(grid: &PartsGrid) -> Result<Answer> {
+        Ok(grid.sum_true_parts().into())
+    }
+
+    fn part2(grid: &PartsGrid) -> Result<Answer> {
+        Ok(grid.sum_gear_ratios().into())
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Answer, Result, Solution};
+use log::{debug, trace};
+use fnv::FnvHashSet;
+
+pub struct Day04;
+
+/// a scratchcard: winning numbers | numbers you have
+pub struct Card {
+    line: String,
+    halves: [FnvHashSet<u32>; 2],
+}
+
+fn parse_card(line: &Line) -> Result<Card> {
+    let (_header, numbers) = line.text.split_once(':')
+        .ok_or_else(|| line.error_at_end("cannot parse card header: expected ':'"))?;
+    let (winning, have) = numbers.split_once('|')
+        .ok_or_else(|| line.error_at_end("expected '|'"))?;
+    let parse_half = |half| -> Result<FnvHashSet<u32>> {
+        Ok(line.numbers(half)?.into_iter().collect())
+    };
+    Ok(Card {
+        line: line.text.to_string(),
+        halves: [parse_half(winning)?, parse_half(have)?],
+    })
+}
+
+fn score_card(card: &Card) -> u32 {
+    let halves = &card.halves;
+    let count = halves[0].intersection(&halves[1]).count() as u32;
+    match count {
+        0 => 0,
+        _ => 2_u32.pow(count - 1)
+    }
+}
+
+fn score_card_part2(card: &Card) -> u32 {
+    let mut x = score_card(card);
+    if x == 0 {
+        return 0;
+    }
+    let mut count = 1;
+    while x & 1 == 0 {
+        x >>= 1;
+        count += 1;
+    }
+    count
+}
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>> {
+        lines(input).map(|line| parse_card(&line)).collect()
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<Answer> {
+        let total = cards.iter()
+            .map(score_card)
+            .sum::<u32>();
+        Ok(total.into())
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<Answer> {
+        let scores : Vec<u32> = cards.iter()
+            .inspect(|card| trace!("{}", card.line))
+            .map(score_card_part2)
+            .collect();
+        debug!("scores: {:?}", scores);
+        let mut counts = vec![1; scores.len()];
+        for i in 0..scores.len() {
+            if i + scores[i] as usize >= scores.len() {
+                return Err(format!("card {} wins copies of cards past the end of the table", i + 1).into());
+            }
+            for j in 1..(scores[i]+1) as usize {
+                counts[i + j] += counts[i];
+            }
+        }
+        Ok(counts.iter().sum::<u32>().into())
+    }
+}
+
+#[test]
+fn test_truncated_table_is_an_error() {
+    let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 4\n").unwrap();
+    let err = Day04::part2(&cards).unwrap_err();
+    assert_eq!(err.to_string(), "card 1 wins copies of cards past the end of the table");
+}
@@ -0,0 +1,542 @@
+/*
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+*/
+
+use aoc_common::interval::IntervalSet;
+use aoc_common::parse::{lines, Line};
+use aoc_common::{AocError, Answer, Result, Solution};
+use log::{debug, trace};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Range;
+
+/// inputs from `start` up to the next piece's start are shifted by `offset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Piece {
+    start: u64,
+    offset: i128,
+}
+
+impl Piece {
+    fn apply(&self, in_val: u64) -> u64 {
+        (in_val as i128 + self.offset) as u64
+    }
+}
+
+/// a piecewise-linear map from one category to the next. The pieces are
+/// sorted and cover every input below `u64::MAX`: the gaps between the
+/// almanac's rules are pieces with offset 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    pub source_category: String,
+    pub destination_category: String,
+    pieces: Vec<Piece>,
+}
+
+impl Map {
+    /// the map sending every value to itself
+    fn identity(category: &str) -> Map {
+        Map::from_rules(category.to_string(), category.to_string(), Vec::new())
+    }
+
+    /// build from (destination start, source start, length) rules,
+    /// which must not overlap
+    fn from_rules(source_category: String, destination_category: String, mut rules: Vec<(u64, u64, u64)>) -> Map {
+        rules.sort_by_key(|&(_, in_start, _)| in_start);
+        let mut pieces = vec![Piece { start: 0, offset: 0 }];
+        for (out_start, in_start, len) in rules {
+            if len == 0 {
+                continue;
+            }
+            let piece = Piece { start: in_start, offset: out_start as i128 - in_start as i128 };
+            match pieces.last_mut() {
+                Some(last) if last.start == in_start => *last = piece,
+                _ => pieces.push(piece),
+            }
+            pieces.push(Piece { start: in_start + len, offset: 0 });
+        }
+        Map { source_category, destination_category, pieces: merge(pieces) }
+    }
+
+    /// parse a map given header and data lines
+    fn parse(lines: &[Line]) -> Result<Map> {
+        let header_line = lines.first().ok_or_else(|| AocError::from("expected map header"))?;
+        let header = header_line.text.strip_suffix(" map:")
+            .ok_or_else(|| header_line.error_at_end("cannot parse map header: expected ' map:'"))?;
+        let (source_category, destination_category) = header.split_once("-to-")
+            .ok_or_else(|| header_line.error(1, "cannot parse map header: expected '<source>-to-<destination>'"))?;
+        let mut rules: Vec<(u64, u64, u64, &Line)> = Vec::new();
+        for line in &lines[1..] {
+            let rule: Vec<u64> = line.numbers(line.text)?;
+            let [out_start, in_start, len] = rule[..] else {
+                return Err(line.error(1, format!("expected 3 numbers, found {}", rule.len())));
+            };
+            if out_start.checked_add(len).is_none() || in_start.checked_add(len).is_none() {
+                return Err(line.error(1, "rule runs past the largest number"));
+            }
+            if let Some(other) = rules.iter().find(|r| in_start < r.1 + r.2 && r.1 < in_start + len) {
+                return Err(line.error(1, format!("rule overlaps the one on line {}", other.3.number)));
+            }
+            rules.push((out_start, in_start, len, line));
+        }
+        let rules = rules.into_iter().map(|(out_start, in_start, len, _)| (out_start, in_start, len)).collect();
+        Ok(Map::from_rules(source_category.to_string(), destination_category.to_string(), rules))
+    }
+
+    /// index of the piece containing `in_val`
+    fn piece_index(&self, in_val: u64) -> usize {
+        self.pieces.partition_point(|piece| piece.start <= in_val) - 1
+    }
+
+    /// first input past piece `i`
+    fn piece_end(&self, i: usize) -> u64 {
+        self.pieces.get(i + 1).map_or(u64::MAX, |piece| piece.start)
+    }
+
+    pub fn lookup(&self, in_val : u64) -> u64 {
+        self.pieces[self.piece_index(in_val)].apply(in_val)
+    }
+
+    /// map a single input range to one or more output ranges
+    pub fn lookup_range(&self, in_range : Range<u64>) -> Vec<Range<u64>> {
+        let mut out_ranges = Vec::new();
+        if in_range.is_empty() {
+            return out_ranges;
+        }
+        let mut i = self.piece_index(in_range.start);
+        let mut start = in_range.start;
+        while start < in_range.end {
+            let end = self.piece_end(i).min(in_range.end);
+            trace!("     piece: {:?} covers {}..{}", self.pieces[i], start, end);
+            let out_start = self.pieces[i].apply(start);
+            out_ranges.push(out_start..out_start + (end - start));
+            start = end;
+            i += 1;
+        }
+        out_ranges
+    }
+
+    /// the map that applies this one and then `next`
+    pub fn compose(&self, next: &Map) -> Result<Map> {
+        if self.destination_category != next.source_category {
+            return Err(format!("cannot follow a map to {} with one from {}",
+                self.destination_category, next.source_category).into());
+        }
+        let mut pieces = Vec::new();
+        for (i, piece) in self.pieces.iter().enumerate() {
+            // split this piece wherever its outputs cross into another of next's pieces
+            let out_end = piece.apply(piece.start) + (self.piece_end(i) - piece.start);
+            let mut out_start = piece.apply(piece.start);
+            let mut j = next.piece_index(out_start);
+            while out_start < out_end {
+                let in_start = (out_start as i128 - piece.offset) as u64;
+                pieces.push(Piece { start: in_start, offset: piece.offset + next.pieces[j].offset });
+                out_start = next.piece_end(j);
+                j += 1;
+            }
+        }
+        Ok(Map {
+            source_category: self.source_category.clone(),
+            destination_category: next.destination_category.clone(),
+            pieces: merge(pieces),
+        })
+    }
+
+    /// the inputs mapping into `out_range`, piece by piece, each with the
+    /// offset that takes it there
+    fn preimage_pieces(&self, out_range: Range<u64>) -> Vec<(Range<u64>, i128)> {
+        let mut found = Vec::new();
+        for (i, piece) in self.pieces.iter().enumerate() {
+            // the range of outputs of this piece that fall in out_range
+            let start = (piece.apply(piece.start)).max(out_range.start);
+            let end = (piece.apply(self.piece_end(i))).min(out_range.end);
+            if start < end {
+                let in_start = (start as i128 - piece.offset) as u64;
+                found.push((in_start..in_start + (end - start), piece.offset));
+            }
+        }
+        found
+    }
+
+    /// every input that maps into `out_range`. Several inputs may map to
+    /// the same output, e.g. through a rule and through a gap between rules.
+    pub fn preimage(&self, out_range: Range<u64>) -> IntervalSet {
+        self.preimage_pieces(out_range).into_iter().map(|(range, _)| range).collect()
+    }
+
+    /// the smallest output for any input in `in_range`. Each piece only
+    /// shifts its inputs, so this is at the start of the range or of a piece.
+    pub fn min_over(&self, in_range: Range<u64>) -> Option<u64> {
+        self.lookup_range(in_range).iter().map(|out| out.start).min()
+    }
+}
+
+/// join neighboring pieces with the same offset
+fn merge(pieces: Vec<Piece>) -> Vec<Piece> {
+    let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+    for piece in pieces {
+        if merged.last().is_none_or(|last| last.offset != piece.offset) {
+            merged.push(piece);
+        }
+    }
+    merged
+}
+
+/// how part 2 finds the lowest location
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// push the seed ranges through each map in turn
+    Forward,
+    /// compose the chain into one seed-to-location map and check the
+    /// start of each of its pieces within the seed ranges
+    Composed,
+    /// look back from ever larger blocks of locations, starting at 0,
+    /// until one of them comes from a seed
+    Reverse,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [Strategy::Forward, Strategy::Composed, Strategy::Reverse];
+}
+
+/// a chain of maps that leads back to where it started, if any
+fn find_cycle(maps: &[Map]) -> Option<Vec<&str>> {
+    // depth first: a category seen again on the current path closes a cycle
+    fn visit<'a>(maps: &'a [Map], category: &'a str, path: &mut Vec<&'a str>, done: &mut HashSet<&'a str>)
+        -> Option<Vec<&'a str>>
+    {
+        if let Some(i) = path.iter().position(|&c| c == category) {
+            let mut cycle = path[i..].to_vec();
+            cycle.push(category);
+            return Some(cycle);
+        }
+        if !done.insert(category) {
+            return None;
+        }
+        path.push(category);
+        for map in maps.iter().filter(|map| map.source_category == category) {
+            if let Some(cycle) = visit(maps, &map.destination_category, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        None
+    }
+    let mut done = HashSet::new();
+    maps.iter().find_map(|map| visit(maps, &map.source_category, &mut Vec::new(), &mut done))
+}
+
+/// the seeds and the maps between categories, in file order
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// the maps that convert `from` into `to`, found by following the
+    /// categories in their headers rather than their order in the file
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&Map>> {
+        for category in [from, to] {
+            if !self.maps.iter().any(|map| map.source_category == category || map.destination_category == category) {
+                return Err(format!("unknown category '{}'", category).into());
+            }
+        }
+        // breadth first, remembering the map that first reached each category
+        let mut reached: HashMap<&str, Option<usize>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            for (i, map) in self.maps.iter().enumerate().filter(|(_, map)| map.source_category == category) {
+                if !reached.contains_key(map.destination_category.as_str()) {
+                    reached.insert(&map.destination_category, Some(i));
+                    queue.push_back(&map.destination_category);
+                }
+            }
+        }
+        let mut route = Vec::new();
+        let mut category = to;
+        while category != from {
+            let i = reached.get(category).copied().flatten()
+                .ok_or_else(|| format!("no chain of maps leads from {} to {}", from, to))?;
+            route.push(&self.maps[i]);
+            category = &self.maps[i].source_category;
+        }
+        route.reverse();
+        Ok(route)
+    }
+
+    /// one map converting `from` into `to`
+    pub fn conversion(&self, from: &str, to: &str) -> Result<Map> {
+        self.route(from, to)?.into_iter()
+            .try_fold(Map::identity(from), |map, next| map.compose(next))
+    }
+
+    /// the maps from seed to location
+    fn chain(&self) -> Result<Vec<&Map>> {
+        self.route("seed", "location")
+    }
+
+    /// part 2 reads the seeds as (start, length) pairs
+    pub fn seed_ranges(&self) -> Result<IntervalSet> {
+        if let [last] = self.seeds.chunks_exact(2).remainder() {
+            return Err(format!("expected a length after seed {}", last).into());
+        }
+        self.seeds.chunks_exact(2)
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1])
+                    .ok_or_else(|| format!("seed range {} + {} runs past the largest number", pair[0], pair[1]))?;
+                Ok(pair[0]..end)
+            })
+            .collect()
+    }
+
+    /// the whole chain of maps as one
+    pub fn seed_to_location(&self) -> Result<Map> {
+        self.conversion("seed", "location")
+    }
+
+    /// every seed whose location is in `locations`
+    pub fn seeds_for_locations(&self, locations: Range<u64>) -> Result<IntervalSet> {
+        let mut ranges = IntervalSet::from_iter([locations]);
+        for map in self.chain()?.iter().rev() {
+            ranges = ranges.iter().flat_map(|range| map.preimage(range)).collect();
+        }
+        Ok(ranges)
+    }
+
+    /// the lowest location in `locations` that some seed range reaches
+    fn lowest_in(&self, chain: &[&Map], locations: Range<u64>, seed_ranges: &IntervalSet) -> Option<u64> {
+        // track each seed range with the offset that takes it to its location
+        let mut pieces = vec![(locations, 0)];
+        for map in chain.iter().rev() {
+            pieces = pieces.into_iter()
+                .flat_map(|(range, offset)| map.preimage_pieces(range).into_iter()
+                    .map(move |(before, shift)| (before, offset + shift)))
+                .collect();
+        }
+        pieces.into_iter()
+            .filter_map(|(range, offset)| {
+                let seeds = IntervalSet::from_iter([range]).intersection(seed_ranges);
+                seeds.min().map(|start| (start as i128 + offset) as u64)
+            })
+            .min()
+    }
+
+    /// lowest location of any seed in the seed ranges
+    pub fn lowest_location(&self, strategy: Strategy) -> Result<u64> {
+        let seed_ranges = self.seed_ranges()?;
+        let lowest = match strategy {
+            Strategy::Forward => {
+                // coalescing after each map keeps the number of ranges
+                // bounded by the pieces of the maps rather than growing
+                let mut ranges = seed_ranges;
+                for map in self.chain()? {
+                    debug!("{} ranges: {:?}", ranges.range_count(), ranges);
+                    debug!("Applying map: {:?}", map);
+                    ranges = ranges.iter()
+                        .flat_map(|range| {
+                            trace!("  range: {:?}", range);
+                            map.lookup_range(range)
+                        })
+                        .collect();
+                }
+                ranges.min()
+            },
+            Strategy::Composed => {
+                let map = self.seed_to_location()?;
+                debug!("seed-to-location map: {} pieces", map.pieces.len());
+                seed_ranges.iter().filter_map(|range| map.min_over(range)).min()
+            },
+            Strategy::Reverse => {
+                let chain = self.chain()?;
+                let mut block = 0..1;
+                loop {
+                    debug!("looking back from locations {:?}", block);
+                    if let Some(lowest) = self.lowest_in(&chain, block.clone(), &seed_ranges) {
+                        break Some(lowest);
+                    }
+                    if block.end == u64::MAX {
+                        break None;
+                    }
+                    let len = (block.end - block.start).saturating_mul(2).min(u64::MAX - block.end);
+                    block = block.end..block.end + len;
+                }
+            },
+        };
+        Ok(lowest.ok_or("no seed ranges")?)
+    }
+
+    /// solve part 2 with every strategy, failing unless they agree
+    pub fn cross_check(&self) -> Result<u64> {
+        let answers = Strategy::ALL.iter()
+            .map(|&strategy| Ok((strategy, self.lowest_location(strategy)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let (_, first) = answers[0];
+        if answers.iter().any(|&(_, lowest)| lowest != first) {
+            return Err(format!("part 2 strategies disagree: {:?}", answers).into());
+        }
+        Ok(first)
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac> {
+        let mut lines = lines(input);
+        // get seeds list from first line
+        let seeds_line = lines.next().ok_or_else(|| AocError::parse(1, 1, "expected seeds"))?;
+        let seeds_text = seeds_line.expect_prefix(seeds_line.text, "seeds:")?;
+        let seeds : Vec<u64> = seeds_line.numbers(seeds_text)?;
+        debug!("seeds: {:?}", seeds);
+
+        // split on blank lines
+        let mut maps: Vec<Map> = Vec::new();
+        let mut add_map = |map_lines: &[Line]| {
+            let map = Map::parse(map_lines)?;
+            if maps.iter().any(|m| (&m.source_category, &m.destination_category) == (&map.source_category, &map.destination_category)) {
+                return Err(map_lines[0].error(1, format!("second map from {} to {}", map.source_category, map.destination_category)));
+            }
+            maps.push(map);
+            Ok(())
+        };
+        if let Some(line) = lines.next() {
+            if !line.text.is_empty() {
+                return Err(line.error(1, "expected a blank line after the seeds"));
+            }
+        }
+        let mut map_lines = Vec::new();
+        for line in lines {
+            if line.text.is_empty() {
+                if map_lines.is_empty() {
+                    return Err(line.error(1, "expected map header, found blank line"));
+                }
+                add_map(&map_lines)?;
+                map_lines = Vec::new();
+            } else {
+                map_lines.push(line);
+            }
+        }
+        if !map_lines.is_empty() {
+            add_map(&map_lines)?;
+        }
+        if let Some(cycle) = find_cycle(&maps) {
+            return Err(format!("maps form a cycle: {}", cycle.join(" -> ")).into());
+        }
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(almanac: &Almanac) -> Result<Answer> {
+        // for each seed, apply each map in order
+        let chain = almanac.chain()?;
+        let mut locations = Vec::new();
+        for seed in almanac.seeds.iter() {
+            let mut val = *seed;
+            for map in chain.iter() {
+                val = map.lookup(val);
+            }
+            locations.push(val);
+        }
+        Ok((*locations.iter().min().ok_or("no seeds")?).into())
+    }
+
+    fn part2(almanac: &Almanac) -> Result<Answer> {
+        Ok(almanac.lowest_location(Strategy::Composed)?.into())
+    }
+}
+
+
+#[test]
+fn test_strategies_agree() {
+    let almanac = Day05::parse(include_str!("../example.input")).unwrap();
+    let composed = almanac.seed_to_location().unwrap();
+    for seed in 0..100 {
+        let chained = almanac.chain().unwrap().iter().fold(seed, |val, map| map.lookup(val));
+        assert_eq!(composed.lookup(seed), chained, "seed {}", seed);
+    }
+    assert_eq!(almanac.cross_check().unwrap(), 46);
+    let almanac = Day05::parse(include_str!("../puzzle.input")).unwrap();
+    assert_eq!(almanac.cross_check().unwrap(), 137516820);
+}
+
+#[test]
+fn test_seeds_for_locations_inverts_the_chain() {
+    let almanac = Day05::parse(include_str!("../example.input")).unwrap();
+    let chain = almanac.chain().unwrap();
+    let location = |seed| chain.iter().fold(seed, |val, map| map.lookup(val));
+    let seeds = almanac.seeds_for_locations(40..60).unwrap();
+    for seed in 0..200 {
+        assert_eq!(seeds.contains(seed), (40..60).contains(&location(seed)), "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_route_follows_categories() {
+    let example = include_str!("../example.input");
+    // the same maps in reverse file order
+    let (seeds, maps) = example.split_once("\n\n").unwrap();
+    let mut maps: Vec<&str> = maps.trim_end().split("\n\n").collect();
+    maps.reverse();
+    let reversed = format!("{}\n\n{}", seeds, maps.join("\n\n"));
+    let almanac = Day05::parse(&reversed).unwrap();
+    assert_eq!(almanac.cross_check().unwrap(), 46);
+    let route = almanac.route("soil", "humidity").unwrap();
+    assert_eq!(route.iter().map(|map| map.destination_category.as_str()).collect::<Vec<_>>(),
+        ["fertilizer", "water", "light", "temperature", "humidity"]);
+    assert_eq!(almanac.conversion("seed", "water").unwrap().lookup(79), 81);
+
+    let err = almanac.route("location", "seed").unwrap_err();
+    assert_eq!(err.to_string(), "no chain of maps leads from location to seed");
+    let err = Day05::parse("seeds: 1\n\na-to-b map:\n1 2 3\n\nb-to-a map:\n4 5 6\n").err().unwrap();
+    assert_eq!(err.to_string(), "maps form a cycle: a -> b -> a");
+}
+
+#[test]
+fn test_parse_rejects_bad_layout() {
+    let err = |input: &str| Day05::parse(input).err().unwrap().to_string();
+    assert_eq!(err("seeds: 1 2\n\na-to-b map:\n1 2 3\n\n\nb-to-c map:\n1 2 3\n"), "6:1: expected map header, found blank line");
+    assert_eq!(err("seeds: 1 2\na-to-b map:\n1 2 3\n"), "2:1: expected a blank line after the seeds");
+}
+
+#[test]
+fn test_seed_ranges_must_fit() {
+    let err = |input: &str| Day05::part2(&Day05::parse(input).unwrap()).err().unwrap().to_string();
+    assert_eq!(err("seeds: 18446744073709551610 10\n\nseed-to-location map:\n1 2 3\n"),
+        "seed range 18446744073709551610 + 10 runs past the largest number");
+    assert_eq!(err("seeds: 79 14 55\n\nseed-to-location map:\n1 2 3\n"), "expected a length after seed 55");
+    // part 1 reads the seeds one at a time, so neither rule applies
+    let almanac = Day05::parse("seeds: 79 14 55\n\nseed-to-location map:\n1 2 3\n").unwrap();
+    assert_eq!(Day05::part1(&almanac).unwrap(), 14.into());
+}
@@ -0,0 +1,289 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+/// unsigned integers the race solver can work in. It squares the race
+/// time, so the type must hold `t * t` without overflowing: `u128` does
+/// for any `u64` input, and `BigUint` (with the `bigint` feature) always does.
+/// `u64` is left out because it doesn't.
+pub trait Exact: Clone + Ord + From<u64>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// the largest `r` with `r * r <= self`
+    fn isqrt(&self) -> Self;
+}
+
+impl Exact for u128 {
+    fn isqrt(&self) -> u128 {
+        u128::isqrt(*self)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Exact for num::BigUint {
+    fn isqrt(&self) -> num::BigUint {
+        num::integer::Roots::sqrt(self)
+    }
+}
+
+/// how many whole-millisecond hold times in a race of `t` ms travel
+/// further than `d`. Holding for `h` travels `h * (t - h)`, which beats
+/// `d` between the roots of `h^2 - t*h + d`, symmetric about `t / 2`.
+pub fn winning_holds<N: Exact>(t: N, d: N) -> N {
+    let two = N::from(2);
+    let beats = |h: &N| h.clone() * (t.clone() - h.clone()) > d;
+    let (square, four_d) = (t.clone() * t.clone(), N::from(4) * d.clone());
+    if square < four_d {
+        return N::from(0);
+    }
+    let s = (square - four_d).isqrt();
+    // (t - s) / 2 is within half a step of the lower root, so at most
+    // one hold below the first winning one
+    let mut lo = (t.clone() - s) / two.clone();
+    let half = t.clone() / two.clone();
+    while lo <= half && !beats(&lo) {
+        lo = lo + N::from(1);
+    }
+    if lo > half {
+        return N::from(0);
+    }
+    // winners are lo..=t - lo
+    t - two * lo + N::from(1)
+}
+
+/// how a boat picks up speed: holding the button for `h` ms gives a speed
+/// of `charge_rate * h`, up to `max_speed`, and the boat sets off `penalty`
+/// ms after the button is let go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoatModel {
+    /// speed gained per millisecond held
+    pub charge_rate: u64,
+    pub max_speed: Option<u64>,
+    /// milliseconds lost between letting go and moving
+    pub penalty: u64,
+}
+
+impl Default for BoatModel {
+    /// the puzzle's boats: one unit of speed per ms, no limit, no delay
+    fn default() -> BoatModel {
+        BoatModel { charge_rate: 1, max_speed: None, penalty: 0 }
+    }
+}
+
+impl BoatModel {
+    /// how far holding for `h` ms of a race of `t` ms goes
+    pub fn distance(&self, t: u64, h: u64) -> u128 {
+        let speed = (self.charge_rate as u128).saturating_mul(h as u128);
+        let speed = self.max_speed.map_or(speed, |max| speed.min(max as u128));
+        speed.saturating_mul(t.saturating_sub(h).saturating_sub(self.penalty) as u128)
+    }
+
+    /// without a speed limit the distance is `rate * h * (t' - h)` for the
+    /// moving time `t' = t - penalty`, a quadratic the exact solver handles
+    fn is_quadratic(&self) -> bool {
+        self.max_speed.is_none()
+    }
+
+    /// the time left to move if the button were never held
+    fn moving_time(&self, t: u64) -> u64 {
+        t.saturating_sub(self.penalty)
+    }
+
+    /// how many hold times in a race of `t` ms go further than `d`
+    pub fn winning_holds(&self, t: u64, d: u64) -> u64 {
+        if self.is_quadratic() {
+            if self.charge_rate == 0 {
+                return 0;
+            }
+            // rate * k > d exactly when k > d / rate, rounding down
+            return winning_holds(self.moving_time(t) as u128, (d / self.charge_rate) as u128) as u64;
+        }
+        let best = self.best_hold(t);
+        if self.distance(t, best) <= d as u128 {
+            return 0;
+        }
+        // the distance rises up to the best hold and falls after it
+        let first = first_true(0, best, |h| self.distance(t, h) > d as u128);
+        let past = first_true(best, t, |h| self.distance(t, h) <= d as u128);
+        let last = if self.distance(t, past) > d as u128 { past } else { past - 1 };
+        last - first + 1
+    }
+
+    /// the hold time that goes furthest in a race of `t` ms, the shortest
+    /// if there is a tie
+    pub fn best_hold(&self, t: u64) -> u64 {
+        if self.is_quadratic() && self.charge_rate > 0 {
+            return self.moving_time(t) / 2;
+        }
+        first_true(0, t, |h| self.distance(t, h) >= self.distance(t, h + 1))
+    }
+
+    /// the shortest race in which some hold time goes further than `d`,
+    /// if any does
+    pub fn min_race_time(&self, d: u64) -> Option<u64> {
+        if self.charge_rate == 0 || self.max_speed == Some(0) {
+            return None;
+        }
+        if self.is_quadratic() {
+            // the best of a race of t' ms goes rate * floor(t'/2) * ceil(t'/2),
+            // which first passes k = d / rate at t' = 2m + 1 or 2m + 2 for m = isqrt(k)
+            let k = d / self.charge_rate;
+            let m = k.isqrt();
+            let moving = if m as u128 * (m as u128 + 1) > k as u128 { 2 * m + 1 } else { 2 * m + 2 };
+            return moving.checked_add(self.penalty);
+        }
+        let beats = |t: u64| self.distance(t, self.best_hold(t)) > d as u128;
+        let mut high = 1u64;
+        while !beats(high) {
+            high = high.checked_mul(2)?;
+        }
+        Some(first_true(high / 2, high, beats))
+    }
+}
+
+/// the first value in `low..=high` where `pred` holds, given that it holds
+/// from some point on; `high` if it never holds before
+fn first_true(mut low: u64, mut high: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::{AocError, Answer, Result, Solution};
+
+/// the numbers after the label on a `Time:` or `Distance:` line
+fn read_values<'a>(line: &Line<'a>, label: &str) -> Result<&'a str> {
+    line.expect_prefix(line.text, label)
+}
+
+// parse input lines
+// example:
+/*
+Time:      7  15   30
+Distance:  9  40  200
+*/
+fn read_input(time_line: &Line, distance_line: &Line) -> Result<Vec<(u64, u64)>> {
+    let times: Vec<u64> = time_line.numbers(read_values(time_line, "Time:")?)?;
+    let distances: Vec<u64> = distance_line.numbers(read_values(distance_line, "Distance:")?)?;
+    if times.len() != distances.len() {
+        return Err(distance_line.error_at_end(
+            format!("expected {} distances, found {}", times.len(), distances.len())));
+    }
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// the digits on a line, ignoring the spaces between them
+fn read_kerned(line: &Line, label: &str) -> Result<u64> {
+    let values = read_values(line, label)?;
+    // check each group of digits first so errors point at the bad character
+    line.numbers::<u64>(values)?;
+    values.split_whitespace().collect::<Vec<&str>>().join("").parse()
+        .map_err(|e| line.error_at(values.trim_start(), format!("invalid number: {}", e)))
+}
+
+fn read_input_part2(time_line: &Line, distance_line: &Line) -> Result<(u64, u64)> {
+    let time: u64 = read_kerned(time_line, "Time:")?;
+    let distance: u64 = read_kerned(distance_line, "Distance:")?;
+    Ok((time, distance))
+}
+
+/// the separate races, and the lines they came from, which part 2 reads
+/// again as one long race
+pub struct Races {
+    races: Vec<(u64, u64)>,
+    time_line: String,
+    distance_line: String,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Races> {
+        let mut lines = lines(input);
+        let time_line = lines.next().ok_or_else(|| AocError::parse(1, 1, "expected Time line"))?;
+        let distance_line = lines.next().ok_or_else(|| AocError::parse(2, 1, "expected Distance line"))?;
+        Ok(Races {
+            races: read_input(&time_line, &distance_line)?,
+            time_line: time_line.text.to_string(),
+            distance_line: distance_line.text.to_string(),
+        })
+    }
+
+    fn part1(races: &Races) -> Result<Answer> {
+        let mut acc = 1;
+        for &(t, d) in &races.races {
+            let opt = BoatModel::default().winning_holds(t, d);
+            acc *= opt;
+        }
+        Ok(acc.into())
+    }
+
+    fn part2(races: &Races) -> Result<Answer> {
+        // the digits run together can be too large even when each race fits
+        let time_line = Line { number: 1, text: &races.time_line };
+        let distance_line = Line { number: 2, text: &races.distance_line };
+        let (time, distance) = read_input_part2(&time_line, &distance_line)?;
+        Ok(BoatModel::default().winning_holds(time, distance).into())
+    }
+}
+
+#[test]
+fn test_winning_holds_match_brute_force() {
+    for t in 0..64u64 {
+        for d in 0..=t * t / 4 + 2 {
+            let expected = (0..=t).filter(|h| h * (t - h) > d).count() as u64;
+            assert_eq!(BoatModel::default().winning_holds(t, d), expected, "t {} d {}", t, d);
+            assert_eq!(winning_holds(t as u128, d as u128), expected as u128, "t {} d {}", t, d);
+        }
+    }
+    // past 2^53, where f64 roots are no longer exact
+    let t = (1u64 << 60) + 1;
+    let best = (t / 2) as u128 * (t - t / 2) as u128;
+    assert_eq!(winning_holds(t as u128, best), 0);
+    assert_eq!(winning_holds(t as u128, best - 1), 2);
+    let half = (t / 2) as u128;
+    assert_eq!(winning_holds(2 * half, half * half - 1), 1);
+    #[cfg(feature = "bigint")]
+    {
+        use num::BigUint;
+        assert_eq!(winning_holds(BigUint::from(t), BigUint::from(best - 1)), BigUint::from(2u64));
+    }
+}
+
+#[test]
+fn test_boat_models_match_brute_force() {
+    for charge_rate in 0..4 {
+        for max_speed in [None, Some(0), Some(1), Some(5), Some(12)] {
+            for penalty in [0, 1, 3] {
+                let model = BoatModel { charge_rate, max_speed, penalty };
+                for t in 0..30 {
+                    let best = (0..=t).max_by_key(|&h| (model.distance(t, h), std::cmp::Reverse(h))).unwrap();
+                    assert_eq!(model.best_hold(t), best, "{:?} t {}", model, t);
+                    for d in 0..60 {
+                        let expected = (0..=t).filter(|&h| model.distance(t, h) > d as u128).count() as u64;
+                        assert_eq!(model.winning_holds(t, d), expected, "{:?} t {} d {}", model, t, d);
+                    }
+                }
+                for d in 0..60 {
+                    let expected = (0..200).find(|&t| model.winning_holds(t, d) > 0);
+                    assert_eq!(model.min_race_time(d), expected, "{:?} d {}", model, d);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_long_race_is_read_by_part2() {
+    let races = Day06::parse("Time: 9999999 9999999 999999\nDistance: 24999994999999 24999994999999 249999499999\n").unwrap();
+    assert_eq!(Day06::part1(&races).unwrap(), 8.into());
+    assert_eq!(Day06::part2(&races).err().unwrap().to_string(),
+        "1:7: invalid number: number too large to fit in target type");
+}
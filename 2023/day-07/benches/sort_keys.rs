@@ -0,0 +1,57 @@
+//! Sorts a million random hands three ways, to compare classifying on every
+//! comparison with classifying once and with sorting by packed keys.
+//!
+//! Run with `cargo bench -p day-07`.
+
+use day_07::{Hand, Ruleset};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const HANDS: usize = 1_000_000;
+
+/// `HANDS` hands of random cards, from a fixed seed so runs are comparable
+fn synthetic_hands(ruleset: &Ruleset) -> Vec<String> {
+    let mut state: u64 = 0x2023_0007;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    (0..HANDS)
+        .map(|_| (0..ruleset.hand_size).map(|_| ruleset.ranking[next() as usize % ruleset.ranking.len()]).collect())
+        .collect()
+}
+
+fn time<T>(name: &str, run: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(run());
+    let elapsed = start.elapsed();
+    println!("  {:<28} {:>10.1?}", name, elapsed);
+    elapsed
+}
+
+fn main() {
+    for (name, ruleset) in [("standard", Ruleset::standard()), ("jokers", Ruleset::jokers())] {
+        let cards = synthetic_hands(&ruleset);
+        println!("{} hands, {} rules:", HANDS, name);
+        let slow = time("classify per comparison", || {
+            let mut sorted = cards.clone();
+            // sort_by_key calls the key function on every comparison
+            sorted.sort_by_key(|cards| ruleset.hand(cards).unwrap());
+            sorted
+        });
+        time("classify once, sort hands", || {
+            let mut hands: Vec<Hand> = cards.iter().map(|cards| ruleset.hand(cards).unwrap()).collect();
+            hands.sort();
+            hands
+        });
+        let fast = time("sort by cached packed key", || {
+            let mut sorted = cards.clone();
+            sorted.sort_by_cached_key(|cards| ruleset.hand(cards).unwrap().packed_key());
+            sorted
+        });
+        println!("  speedup: {:.1}x", slow.as_secs_f64() / fast.as_secs_f64());
+    }
+}
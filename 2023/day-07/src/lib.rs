@@ -0,0 +1,297 @@
+use aoc_common::parse::lines;
+use aoc_common::{Answer, Result, Solution};
+use std::collections::HashMap;
+
+/// a kind of hand, by the sizes of its groups of matching cards, largest
+/// first: a full house is `[3, 2]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category {
+    pub name: String,
+    pub groups: Vec<usize>,
+}
+
+impl Category {
+    pub fn new(name: &str, groups: &[usize]) -> Category {
+        Category { name: name.to_string(), groups: groups.to_vec() }
+    }
+
+    /// whether a hand whose groups, largest first, are `groups` has at
+    /// least this category's groups
+    fn fits(&self, groups: &[usize]) -> bool {
+        self.groups.len() <= groups.len() && self.groups.iter().zip(groups).all(|(need, have)| have >= need)
+    }
+}
+
+/// how Camel Cards is played: which cards beat which, which are wild,
+/// how many make a hand, and which hands beat which
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    /// card labels, weakest first
+    pub ranking: Vec<char>,
+    /// cards that count as whichever card makes the best hand. When
+    /// breaking ties they rank as themselves.
+    pub wildcards: Vec<char>,
+    pub hand_size: usize,
+    /// weakest first; a hand takes the strongest category it fits
+    pub categories: Vec<Category>,
+}
+
+impl Ruleset {
+    /// part 1: no wildcards
+    pub fn standard() -> Ruleset {
+        Ruleset {
+            ranking: "23456789TJQKA".chars().collect(),
+            wildcards: Vec::new(),
+            hand_size: 5,
+            categories: vec![
+                Category::new("high card", &[1]),
+                Category::new("one pair", &[2]),
+                Category::new("two pair", &[2, 2]),
+                Category::new("three of a kind", &[3]),
+                Category::new("full house", &[3, 2]),
+                Category::new("four of a kind", &[4]),
+                Category::new("five of a kind", &[5]),
+            ],
+        }
+    }
+
+    /// part 2: jokers are wild, and the weakest card on their own
+    pub fn jokers() -> Ruleset {
+        Ruleset {
+            ranking: "J23456789TQKA".chars().collect(),
+            wildcards: vec!['J'],
+            ..Ruleset::standard()
+        }
+    }
+
+    /// place of `card` in the ranking
+    fn rank(&self, card: char) -> Option<usize> {
+        self.ranking.iter().position(|&c| c == card)
+    }
+
+    fn is_wild(&self, rank: usize) -> bool {
+        self.wildcards.contains(&self.ranking[rank])
+    }
+
+    pub fn hand(&self, cards: &str) -> Result<Hand> {
+        let ranks = cards.chars()
+            .map(|card| self.rank(card).ok_or_else(|| format!("unknown card '{}'", card)))
+            .collect::<Result<Vec<usize>, String>>()?;
+        if ranks.len() != self.hand_size {
+            return Err(format!("expected {} cards, found {}", self.hand_size, ranks.len()).into());
+        }
+        Ok(Hand { category: self.classify(&ranks), ranks })
+    }
+
+    /// index of the strongest category the cards make, counting the
+    /// wildcards into the largest group. That is the best use of them as
+    /// long as growing the largest group never makes a hand weaker, as with
+    /// the standard categories; `verify_classifier` checks a ruleset.
+    pub fn classify(&self, ranks: &[usize]) -> usize {
+        let fixed: Vec<usize> = ranks.iter().copied().filter(|&rank| !self.is_wild(rank)).collect();
+        let wild = ranks.len() - fixed.len();
+        let mut groups = groups(&fixed);
+        match groups.first_mut() {
+            Some(largest) => *largest += wild,
+            None if wild > 0 => groups.push(wild),
+            None => {},
+        }
+        self.category_of(&groups)
+    }
+
+    /// the strongest category of any hand made by replacing each wildcard
+    /// with any card, each independently
+    pub fn classify_by_substitution(&self, ranks: &[usize]) -> usize {
+        let substitutes: Vec<usize> = (0..self.ranking.len()).filter(|&rank| !self.is_wild(rank)).collect();
+        let wild: Vec<usize> = (0..ranks.len()).filter(|&i| self.is_wild(ranks[i])).collect();
+        if wild.is_empty() {
+            return self.category_of(&groups(ranks));
+        }
+        if substitutes.is_empty() {
+            // every card is wild, so they can only stand for each other
+            return self.category_of(&[ranks.len()]);
+        }
+        let mut cards = ranks.to_vec();
+        let mut choice = vec![0; wild.len()];
+        let mut best = 0;
+        loop {
+            for (&i, &c) in wild.iter().zip(&choice) {
+                cards[i] = substitutes[c];
+            }
+            best = best.max(self.category_of(&groups(&cards)));
+            // count through the choices as base-n numbers
+            match choice.iter().rposition(|&c| c + 1 < substitutes.len()) {
+                Some(j) => {
+                    choice[j] += 1;
+                    choice[j + 1..].fill(0);
+                },
+                None => return best,
+            }
+        }
+    }
+
+    /// check `classify` against `classify_by_substitution` for every
+    /// possible hand, returning how many there were
+    pub fn verify_classifier(&self) -> Result<usize> {
+        let mut ranks = vec![0; self.hand_size];
+        let mut checked = 0;
+        // neither classifier cares about the order of the cards, so each
+        // set of cards only needs substituting once
+        let mut substituted: HashMap<Vec<usize>, usize> = HashMap::new();
+        loop {
+            let mut sorted = ranks.clone();
+            sorted.sort_unstable();
+            let slow = *substituted.entry(sorted).or_insert_with_key(|cards| self.classify_by_substitution(cards));
+            let fast = self.classify(&ranks);
+            if fast != slow {
+                let hand: String = ranks.iter().map(|&rank| self.ranking[rank]).collect();
+                return Err(format!("{}: classified as {}, but substitution makes a {}",
+                    hand, self.categories[fast].name, self.categories[slow].name).into());
+            }
+            checked += 1;
+            // count through the hands as base-n numbers
+            match ranks.iter().rposition(|&rank| rank + 1 < self.ranking.len()) {
+                Some(i) => {
+                    ranks[i] += 1;
+                    ranks[i + 1..].fill(0);
+                },
+                None => return Ok(checked),
+            }
+        }
+    }
+
+    fn category_of(&self, groups: &[usize]) -> usize {
+        self.categories.iter().rposition(|category| category.fits(groups)).unwrap_or(0)
+    }
+}
+
+/// sizes of the groups of equal cards, largest first
+fn groups(ranks: &[usize]) -> Vec<usize> {
+    let mut sorted = ranks.to_vec();
+    sorted.sort_unstable();
+    let mut groups: Vec<usize> = sorted.chunk_by(|a, b| a == b).map(|group| group.len()).collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    groups
+}
+
+/// a hand classified under a ruleset. Hands from the same ruleset order
+/// by category, then card by card in the order dealt.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    /// index into the ruleset's categories
+    pub category: usize,
+    /// each card's place in the ranking
+    pub ranks: Vec<usize>,
+}
+
+impl Hand {
+    /// the hand as one number that orders the same way among hands of the
+    /// same ruleset: the category, then four bits per card. None if the
+    /// categories, ranks or cards are too many to fit.
+    pub fn packed_key(&self) -> Option<u32> {
+        if self.category >= 16 || self.ranks.len() > 7 || self.ranks.iter().any(|&rank| rank >= 16) {
+            return None;
+        }
+        Some(self.ranks.iter().fold(self.category as u32, |key, &rank| key << 4 | rank as u32))
+    }
+}
+
+/// rank hands (weakest first) under the ruleset and sum bid * rank
+fn total_winnings(hands_and_bids: &[(String, u64)], ruleset: &Ruleset) -> Result<u64> {
+    let mut hands_and_bids = hands_and_bids.iter()
+        .map(|(hand, bid)| Ok((ruleset.hand(hand)?, *bid)))
+        .collect::<Result<Vec<(Hand, u64)>>>()?;
+    if hands_and_bids.iter().all(|(hand, _)| hand.packed_key().is_some()) {
+        hands_and_bids.sort_by_cached_key(|(hand, _)| hand.packed_key());
+    } else {
+        hands_and_bids.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+    let mut score = 0;
+    for (i, hand_and_bid) in hands_and_bids.iter().enumerate() {
+        let bid = hand_and_bid.1;
+        score += (i + 1) as u64 * bid;
+    }
+    Ok(score)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(String, u64)>;
+
+    fn parse(input: &str) -> Result<Vec<(String, u64)>> {
+        let ruleset = Ruleset::standard();
+        // input lines are: <hand-str> <bid>
+        let mut hands_and_bids: Vec<(String, u64)> = Vec::new();
+        for line in lines(input) {
+            let mut parts = line.words();
+            let hand = line.expect(parts.next(), "hand")?;
+            if let Some((i, c)) = hand.char_indices().find(|&(_, c)| ruleset.rank(c).is_none()) {
+                return Err(line.error_at(&hand[i..], format!("expected card, found '{}'", c)));
+            }
+            if hand.len() != ruleset.hand_size {
+                return Err(line.error_at(hand, format!("expected {} cards, found {}", ruleset.hand_size, hand.len())));
+            }
+            let bid = line.number::<u64>(line.expect(parts.next(), "bid")?)?;
+            hands_and_bids.push((hand.to_string(), bid));
+        }
+        Ok(hands_and_bids)
+    }
+
+    fn part1(hands_and_bids: &Vec<(String, u64)>) -> Result<Answer> {
+        Ok(total_winnings(hands_and_bids, &Ruleset::standard())?.into())
+    }
+
+    fn part2(hands_and_bids: &Vec<(String, u64)>) -> Result<Answer> {
+        Ok(total_winnings(hands_and_bids, &Ruleset::jokers())?.into())
+    }
+}
+
+#[test]
+fn test_variants_are_configuration() {
+    let category = |ruleset: &Ruleset, cards| {
+        let hand = ruleset.hand(cards).unwrap();
+        ruleset.categories[hand.category].name.clone()
+    };
+    let standard = Ruleset::standard();
+    assert_eq!(category(&standard, "KTJJT"), "two pair");
+    assert_eq!(category(&Ruleset::jokers(), "KTJJT"), "four of a kind");
+
+    let two_jokers = Ruleset { wildcards: vec!['J', 'Q'], ..Ruleset::jokers() };
+    assert_eq!(category(&two_jokers, "JQ234"), "three of a kind");
+
+    let aces_low = Ruleset { ranking: "A23456789TJQK".chars().collect(), ..Ruleset::standard() };
+    assert!(standard.hand("A2345").unwrap() > standard.hand("23456").unwrap());
+    assert!(aces_low.hand("A2345").unwrap() < aces_low.hand("23456").unwrap());
+
+    let mut six_cards = Ruleset { hand_size: 6, ..Ruleset::standard() };
+    six_cards.categories.push(Category::new("six of a kind", &[6]));
+    assert_eq!(category(&six_cards, "999999"), "six of a kind");
+    assert_eq!(category(&six_cards, "99922K"), "full house");
+    assert!(standard.hand("999999").is_err());
+}
+
+#[test]
+fn test_classifier_matches_substitution() {
+    assert_eq!(Ruleset::jokers().verify_classifier().unwrap(), 13usize.pow(5));
+    let two_jokers = Ruleset { wildcards: vec!['J', 'Q'], hand_size: 4, ..Ruleset::jokers() };
+    assert_eq!(two_jokers.verify_classifier().unwrap(), 13usize.pow(4));
+    let mut six_cards = Ruleset { hand_size: 6, ranking: "J2345".chars().collect(), ..Ruleset::jokers() };
+    six_cards.categories.push(Category::new("six of a kind", &[6]));
+    assert_eq!(six_cards.verify_classifier().unwrap(), 5usize.pow(6));
+}
+
+#[test]
+fn test_packed_keys_order_like_hands() {
+    let hands_and_bids = Day07::parse(include_str!("../puzzle.input")).unwrap();
+    for ruleset in [Ruleset::standard(), Ruleset::jokers()] {
+        let mut hands: Vec<Hand> = hands_and_bids.iter().map(|(cards, _)| ruleset.hand(cards).unwrap()).collect();
+        hands.sort();
+        for pair in hands.windows(2) {
+            assert!(pair[0].packed_key() < pair[1].packed_key(), "{:?}", pair);
+        }
+    }
+    let mut wide = Ruleset::standard();
+    wide.ranking.extend("BCDEFGH".chars());
+    assert_eq!(wide.hand("HH234").unwrap().packed_key(), None);
+}
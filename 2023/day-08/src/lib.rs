@@ -0,0 +1,538 @@
+
+
+/* EXAMPLE INPUT:
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+*/
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::{AocError, Answer, Result, Solution};
+use std::collections::{HashMap, HashSet};
+use num::integer::{ExtendedGcd, Integer};
+
+/// one instruction: which neighbor to go to next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// the desert's nodes, named by dense ids in the order they first
+/// appear, with each node's left and right neighbors in two arrays
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, u16>,
+    left: Vec<u16>,
+    right: Vec<u16>,
+}
+
+impl Network {
+    /// the id of `name`, giving it the next one if it is new
+    fn intern(&mut self, name: &str) -> Result<u16> {
+        if let Some(&id) = self.ids.get(name) {
+            return Ok(id);
+        }
+        // u16::MAX is kept back to mean "leads nowhere"
+        if self.names.len() >= u16::MAX as usize {
+            return Err("too many nodes".into());
+        }
+        let id = self.names.len() as u16;
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        // until its own line says otherwise, a node leads nowhere
+        self.left.push(u16::MAX);
+        self.right.push(u16::MAX);
+        Ok(id)
+    }
+
+    pub fn id(&self, name: &str) -> Option<u16> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u16) -> &str {
+        &self.names[id as usize]
+    }
+
+    /// every node's id
+    pub fn nodes(&self) -> impl Iterator<Item = u16> {
+        0..self.names.len() as u16
+    }
+
+    pub fn left(&self, id: u16) -> u16 {
+        self.left[id as usize]
+    }
+
+    pub fn right(&self, id: u16) -> u16 {
+        self.right[id as usize]
+    }
+
+    pub fn step(&self, id: u16, turn: Turn) -> u16 {
+        match turn {
+            Turn::Left => self.left(id),
+            Turn::Right => self.right(id),
+        }
+    }
+}
+
+/// reads a node's line, `KEY = (LEFT, RIGHT)`, from left to right
+struct Cursor<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: Line<'a>) -> Cursor<'a> {
+        Cursor { line, rest: line.text }
+    }
+
+    /// what the cursor is looking at, for error messages
+    fn found(&self) -> String {
+        match self.rest.chars().next() {
+            Some(c) => format!("'{}'", c),
+            None => "end of line".to_string(),
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start_matches(' ');
+    }
+
+    /// a node name: one or more letters and digits
+    fn name(&mut self) -> Result<&'a str> {
+        self.skip_spaces();
+        let end = self.rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(self.line.error_at(self.rest, format!("expected node name, found {}", self.found())));
+        }
+        let (name, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Ok(name)
+    }
+
+    fn punct(&mut self, punct: char) -> Result<()> {
+        self.skip_spaces();
+        match self.rest.strip_prefix(punct) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            },
+            None => Err(self.line.error_at(self.rest, format!("expected '{}', found {}", punct, self.found()))),
+        }
+    }
+
+    fn end(&mut self) -> Result<()> {
+        self.skip_spaces();
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.line.error_at(self.rest, format!("expected end of line, found {}", self.found())))
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Turn>, Network)> {
+    let mut network = Network::default();
+    // the line that defines each node, and where each is first mentioned
+    let mut defined: HashMap<u16, usize> = HashMap::new();
+    let mut mentioned: HashMap<u16, (usize, usize)> = HashMap::new();
+    // first line is [RL]+
+    let mut lines = lines(input);
+    let line = lines.next().ok_or_else(|| AocError::parse(1, 1, "missing instructions"))?;
+    if line.text.is_empty() {
+        return Err(line.error(1, "expected instructions"));
+    }
+    line.expect_chars("LR")?;
+    // so every character is an L or an R
+    let instr = line.text.chars().map(|c| if c == 'L' { Turn::Left } else { Turn::Right }).collect();
+    if let Some(line) = lines.next() {
+        if !line.text.is_empty() {
+            return Err(line.error(1, "expected a blank line after the instructions"));
+        }
+    }
+    for line in lines {
+        let mut cursor = Cursor::new(line);
+        let key = cursor.name()?;
+        cursor.punct('=')?;
+        cursor.punct('(')?;
+        let left = cursor.name()?;
+        cursor.punct(',')?;
+        let right = cursor.name()?;
+        cursor.punct(')')?;
+        cursor.end()?;
+
+        let id = network.intern(key)?;
+        if let Some(first) = defined.insert(id, line.number) {
+            return Err(line.error_at(key, format!("node {} is already defined on line {}", key, first)));
+        }
+        let (left_id, right_id) = (network.intern(left)?, network.intern(right)?);
+        network.left[id as usize] = left_id;
+        network.right[id as usize] = right_id;
+        for (name, neighbor) in [(left, left_id), (right, right_id)] {
+            mentioned.entry(neighbor).or_insert((line.number, line.column_of(name)));
+        }
+    }
+    if let Some(id) = network.nodes().find(|id| !defined.contains_key(id)) {
+        let (line, column) = mentioned[&id];
+        return Err(AocError::parse(line, column, format!("node {} is never defined", network.name(id))));
+    }
+    Ok((instr, network))
+}
+
+/// where one whole pass through the instructions leads from each node,
+/// and the steps of the pass that land on a goal
+pub struct JumpTable {
+    pass_len: u64,
+    end: Vec<u16>,
+    hits: Vec<Vec<u32>>,
+}
+
+impl JumpTable {
+    pub fn new(instr: &[Turn], network: &Network, is_goal: impl Fn(u16) -> bool) -> JumpTable {
+        let (end, hits) = network.nodes()
+            .map(|start| {
+                let mut pos = start;
+                let mut hits = Vec::new();
+                for (i, &turn) in instr.iter().enumerate() {
+                    pos = network.step(pos, turn);
+                    if is_goal(pos) {
+                        hits.push(i as u32 + 1);
+                    }
+                }
+                (pos, hits)
+            })
+            .unzip();
+        JumpTable { pass_len: instr.len() as u64, end, hits }
+    }
+}
+
+fn part1(instr: &[Turn], network: &Network) -> Result<u64> {
+    let (start, goal) = match (network.id("AAA"), network.id("ZZZ")) {
+        (Some(start), Some(goal)) => (start, goal),
+        (None, _) => return Err("no AAA node".into()),
+        (_, None) => return Err("no ZZZ node".into()),
+    };
+    let table = JumpTable::new(instr, network, |id| id == goal);
+    let mut pos = start;
+    // a pass from each node at most, or the walk is going round without ZZZ
+    for pass in 0..network.names.len() as u64 {
+        if let Some(&hit) = table.hits[pos as usize].first() {
+            return Ok(pass * table.pass_len + hit as u64);
+        }
+        pos = table.end[pos as usize];
+    }
+    Err("the walk from AAA never reaches ZZZ".into())
+}
+
+/// one ghost's walk. Its state is its node and its place in the
+/// instructions, so once a state repeats the walk repeats with it: after
+/// `prefix` steps it goes round a cycle of `cycle` steps forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub prefix: u64,
+    pub cycle: u64,
+    /// steps before the cycle at which the ghost is on a Z node
+    pub early_hits: Vec<u64>,
+    /// steps in the first time round the cycle at which it is on a Z
+    /// node; each comes round again every `cycle` steps
+    pub cycle_hits: Vec<u64>,
+}
+
+impl Walk {
+    /// whether the ghost is on a Z node after `t` steps
+    pub fn hits(&self, t: u64) -> bool {
+        if t < self.prefix {
+            self.early_hits.contains(&t)
+        } else {
+            self.cycle_hits.contains(&(self.prefix + (t - self.prefix) % self.cycle))
+        }
+    }
+}
+
+/// follow a ghost from `start`, a whole pass of the instructions at a
+/// time, until it starts a pass from the same node again
+pub fn walk(table: &JumpTable, start: u16) -> Walk {
+    let mut pass_start: Vec<Option<u64>> = vec![None; table.end.len()];
+    let mut pos = start;
+    let mut hits = Vec::new();
+    let mut pass = 0;
+    loop {
+        if let Some(first) = pass_start[pos as usize] {
+            let (prefix, cycle) = (first * table.pass_len, (pass - first) * table.pass_len);
+            let (early_hits, mut cycle_hits): (Vec<u64>, Vec<u64>) = hits.iter().partition(|&&t| t < prefix);
+            // the last step of the cycle is the same as the first
+            for t in cycle_hits.iter_mut() {
+                *t = prefix + (*t - prefix) % cycle;
+            }
+            cycle_hits.sort_unstable();
+            cycle_hits.dedup();
+            return Walk { prefix, cycle, early_hits, cycle_hits };
+        }
+        pass_start[pos as usize] = Some(pass);
+        hits.extend(table.hits[pos as usize].iter().map(|&hit| pass * table.pass_len + hit as u64));
+        pos = table.end[pos as usize];
+        pass += 1;
+    }
+}
+
+/// the solutions of `x = a1 (mod m1)` and `x = a2 (mod m2)` as one
+/// congruence `x = a (mod lcm(m1, m2))`, if there are any
+fn crt((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Result<Option<(i128, i128)>> {
+    let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+    if (a2 - a1) % gcd != 0 {
+        return Ok(None);
+    }
+    let overflow = || AocError::from("the ghosts' cycles are too long to combine");
+    let m = (m1 / gcd).checked_mul(m2).ok_or_else(overflow)?;
+    // x * m1 = gcd (mod m2), so stepping (a2 - a1) / gcd of those from a1 reaches a2
+    let k = ((a2 - a1) / gcd % (m2 / gcd)).checked_mul(x % (m2 / gcd)).ok_or_else(overflow)? % (m2 / gcd);
+    let a = (a1 + k.checked_mul(m1).ok_or_else(overflow)?).rem_euclid(m);
+    Ok(Some((a, m)))
+}
+
+/// the first step, if any, after which every ghost is on a Z node
+pub fn first_meeting(walks: &[Walk]) -> Result<Option<u64>> {
+    let Some(latest) = walks.iter().max_by_key(|walk| walk.prefix) else {
+        return Ok(None);
+    };
+    // before every ghost is in its cycle, the latest one can only be on one of its early hits
+    if let Some(&t) = latest.early_hits.iter().find(|&&t| walks.iter().all(|walk| walk.hits(t))) {
+        return Ok(Some(t));
+    }
+    // after that, each ghost picks one of its cycle hits and the steps
+    // that agree with every pick are a congruence
+    let mut classes: HashSet<(i128, i128)> = HashSet::from([(0, 1)]);
+    for walk in walks {
+        let mut next = HashSet::new();
+        for &class in &classes {
+            for &hit in &walk.cycle_hits {
+                if let Some(combined) = crt(class, (hit as i128, walk.cycle as i128))? {
+                    next.insert(combined);
+                }
+            }
+        }
+        classes = next;
+    }
+    // the first step of each class once every ghost is in its cycle
+    // (step 0, where the ghosts start, doesn't count)
+    let start = latest.prefix.max(1) as i128;
+    let first = classes.iter()
+        .map(|&(a, m)| start + (a - start).rem_euclid(m))
+        .min();
+    first.map(|t| u64::try_from(t).map_err(|_| "the ghosts meet too late to count".into())).transpose()
+}
+
+fn part2(instr: &[Turn], network: &Network) -> Result<u64> {
+    let is_goal = |id| network.name(id).ends_with('Z');
+    let table = JumpTable::new(instr, network, is_goal);
+    let walks: Vec<Walk> = network.nodes()
+        .filter(|&id| network.name(id).ends_with('A'))
+        .map(|start| walk(&table, start))
+        .collect();
+    if walks.is_empty() {
+        return Err("no nodes ending in A".into());
+    }
+    first_meeting(&walks)?.ok_or_else(|| "the ghosts are never all on Z nodes at once".into())
+}
+
+/// how an edge of a diagram is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mark {
+    Plain,
+    /// walked from the start before the cycle
+    Path,
+    /// walked round and round
+    Cycle,
+}
+
+/// a kind of text diagram
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagram {
+    /// Graphviz, for `dot -Tsvg`
+    Dot,
+    /// a Mermaid flowchart
+    Mermaid,
+}
+
+/// the edges walked from `start` until the walk repeats, by node and turn
+fn walked_edges(instr: &[Turn], network: &Network, start: u16) -> HashMap<(u16, Turn), Mark> {
+    let table = JumpTable::new(instr, network, |id| network.name(id).ends_with('Z'));
+    let walk = walk(&table, start);
+    let mut marks = HashMap::new();
+    let mut pos = start;
+    for t in 0..walk.prefix + walk.cycle {
+        let turn = instr[t as usize % instr.len()];
+        let mark = if t < walk.prefix { Mark::Path } else { Mark::Cycle };
+        let edge: &mut Mark = marks.entry((pos, turn)).or_insert(mark);
+        *edge = (*edge).max(mark);
+        pos = network.step(pos, turn);
+    }
+    marks
+}
+
+/// the network as a diagram, with its start (`..A`) and goal (`..Z`)
+/// nodes filled in. Given a `start`, the path walked from it is drawn in
+/// blue and the cycle it ends up in in red.
+pub fn diagram(instr: &[Turn], network: &Network, start: Option<u16>, kind: Diagram) -> String {
+    let marks = start.map(|start| walked_edges(instr, network, start)).unwrap_or_default();
+    // one edge per neighbor, labelled with the turns that lead there
+    let mut edges = Vec::new();
+    for id in network.nodes() {
+        let mark = |turn| marks.get(&(id, turn)).copied().unwrap_or(Mark::Plain);
+        if network.left(id) == network.right(id) {
+            edges.push((id, network.left(id), "L,R", mark(Turn::Left).max(mark(Turn::Right))));
+        } else {
+            edges.push((id, network.left(id), "L", mark(Turn::Left)));
+            edges.push((id, network.right(id), "R", mark(Turn::Right)));
+        }
+    }
+    let fill = |id: u16| match network.name(id) {
+        name if name.ends_with('A') => Some("start"),
+        name if name.ends_with('Z') => Some("goal"),
+        _ => None,
+    };
+    let mut out = Vec::new();
+    match kind {
+        Diagram::Dot => {
+            out.push("digraph network {".to_string());
+            for id in network.nodes() {
+                match fill(id) {
+                    Some("start") => out.push(format!("    \"{}\" [style=filled, fillcolor=palegreen];", network.name(id))),
+                    Some(_) => out.push(format!("    \"{}\" [style=filled, fillcolor=lightcoral];", network.name(id))),
+                    None => {},
+                }
+            }
+            for (from, to, label, mark) in edges {
+                let style = match mark {
+                    Mark::Plain => "",
+                    Mark::Path => ", color=blue, penwidth=2",
+                    Mark::Cycle => ", color=red, penwidth=2",
+                };
+                out.push(format!("    \"{}\" -> \"{}\" [label=\"{}\"{}];", network.name(from), network.name(to), label, style));
+            }
+            out.push("}".to_string());
+        },
+        Diagram::Mermaid => {
+            out.push("flowchart LR".to_string());
+            // names can clash with Mermaid keywords such as `end`, so the ids stand in for them
+            for id in network.nodes() {
+                out.push(format!("    n{}[\"{}\"]", id, network.name(id)));
+            }
+            for &(from, to, label, mark) in &edges {
+                let arrow = if mark == Mark::Plain { "-->" } else { "==>" };
+                out.push(format!("    n{} {}|{}| n{}", from, arrow, label, to));
+            }
+            out.push("    classDef start fill:#9f9".to_string());
+            out.push("    classDef goal fill:#f99".to_string());
+            for id in network.nodes() {
+                if let Some(class) = fill(id) {
+                    out.push(format!("    class n{} {}", id, class));
+                }
+            }
+            for (i, &(_, _, _, mark)) in edges.iter().enumerate() {
+                match mark {
+                    Mark::Plain => {},
+                    Mark::Path => out.push(format!("    linkStyle {} stroke:blue", i)),
+                    Mark::Cycle => out.push(format!("    linkStyle {} stroke:red", i)),
+                }
+            }
+        },
+    }
+    out.push(String::new());
+    out.join("\n")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Turn>, Network);
+
+    fn parse(input: &str) -> Result<(Vec<Turn>, Network)> {
+        parse_input(input)
+    }
+
+    fn part1((instr, network): &(Vec<Turn>, Network)) -> Result<Answer> {
+        Ok(part1(instr, network)?.into())
+    }
+
+    fn part2((instr, network): &(Vec<Turn>, Network)) -> Result<Answer> {
+        Ok(part2(instr, network)?.into())
+    }
+}
+
+#[test]
+fn test_ghosts_meet_by_crt() {
+    let input = "L\n\n\
+        1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1Z, 1Z)\n\
+        2A = (2B, 2B)\n2B = (2Z, 2Z)\n2Z = (2A, 2A)\n\
+        3A = (3Z, 3Z)\n3Z = (3QZ, 3QZ)\n3QZ = (3B, 3B)\n3B = (3A, 3A)\n\
+        4A = (4B, 4B)\n4B = (4Z, 4Z)\n4Z = (4C, 4C)\n4C = (4Z, 4Z)\n\
+        5A = (5Z, 5Z)\n5Z = (5B, 5B)\n5B = (5B, 5B)\n";
+    let (instr, network) = Day08::parse(input).unwrap();
+    let table = JumpTable::new(&instr, &network, |id| network.name(id).ends_with('Z'));
+    let walk = |start: &str| walk(&table, network.id(start).unwrap());
+    assert_eq!(walk("3A"), Walk { prefix: 0, cycle: 4, early_hits: vec![], cycle_hits: vec![1, 2] });
+    assert_eq!(walk("4A"), Walk { prefix: 2, cycle: 2, early_hits: vec![], cycle_hits: vec![2] });
+    assert_eq!(walk("5A"), Walk { prefix: 2, cycle: 1, early_hits: vec![1], cycle_hits: vec![] });
+
+    let meeting = |starts: &[&str]| first_meeting(&starts.iter().map(|&start| walk(start)).collect::<Vec<_>>()).unwrap();
+    // odd steps, 2 mod 3, and 1 or 2 mod 4
+    assert_eq!(meeting(&["1A", "2A", "3A"]), Some(5));
+    assert_eq!(meeting(&["2A", "4A"]), Some(2));
+    assert_eq!(meeting(&["1A", "5A"]), Some(1));
+    // odd steps against even ones
+    assert_eq!(meeting(&["1A", "4A"]), None);
+    assert_eq!(meeting(&["2A", "5A"]), None);
+}
+
+#[test]
+fn test_diagrams_mark_the_walk() {
+    let (instr, network) = Day08::parse(include_str!("../example.input")).unwrap();
+    let start = network.id("AAA");
+    let dot = diagram(&instr, &network, start, Diagram::Dot);
+    assert!(dot.starts_with("digraph network {\n    \"AAA\" [style=filled, fillcolor=palegreen];\n"));
+    assert!(dot.contains("    \"ZZZ\" [style=filled, fillcolor=lightcoral];\n"));
+    // RL: right to CCC, left to ZZZ, then round ZZZ for good
+    assert!(dot.contains("    \"AAA\" -> \"CCC\" [label=\"R\", color=blue, penwidth=2];\n"));
+    assert!(dot.contains("    \"CCC\" -> \"ZZZ\" [label=\"L\", color=blue, penwidth=2];\n"));
+    assert!(dot.contains("    \"ZZZ\" -> \"ZZZ\" [label=\"L,R\", color=red, penwidth=2];\n"));
+    assert!(dot.contains("    \"AAA\" -> \"BBB\" [label=\"L\"];\n"));
+
+    let mermaid = diagram(&instr, &network, start, Diagram::Mermaid);
+    assert!(mermaid.starts_with("flowchart LR\n    n0[\"AAA\"]\n"));
+    assert!(mermaid.contains("    n0 ==>|R| n2\n"));
+    assert!(mermaid.contains("    class n0 start\n"));
+    assert_eq!(mermaid.matches("linkStyle").count(), 3);
+    assert!(!diagram(&instr, &network, None, Diagram::Mermaid).contains("==>"));
+}
+
+#[test]
+fn test_parse_errors_point_at_the_problem() {
+    let err = |input: &str| Day08::parse(input).err().unwrap().to_string();
+    assert_eq!(err("LRX\n\nAAA = (AAA, AAA)\n"), "1:3: unexpected character 'X'");
+    assert_eq!(err("LR\nAAA = (AAA, AAA)\n"), "2:1: expected a blank line after the instructions");
+    assert_eq!(err("L\n\nAAA = (AAA AAA)\n"), "3:12: expected ',', found 'A'");
+    assert_eq!(err("L\n\nAAA = (AAA, -)\n"), "3:13: expected node name, found '-'");
+    assert_eq!(err("L\n\nAAA = (AAA, AAA\n"), "3:16: expected ')', found end of line");
+    assert_eq!(err("L\n\nAAA = (AAA, AAA)!\n"), "3:17: expected end of line, found '!'");
+    assert_eq!(err("L\n\nAAA = (BBB, AAA)\nAAA = (AAA, AAA)\n"), "4:1: node AAA is already defined on line 3");
+    assert_eq!(err("L\n\nAAA = (AAA, AAA)\nBBB = (AAA, CCC)\n"), "4:13: node CCC is never defined");
+}
+
+#[test]
+fn test_node_ids_stop_short_of_the_sentinel() {
+    let mut network = Network::default();
+    for i in 0..u16::MAX {
+        assert_eq!(network.intern(&i.to_string()).unwrap(), i);
+    }
+    assert_eq!(network.intern("one too many").unwrap_err().to_string(), "too many nodes");
+    assert_eq!(network.intern("0").unwrap(), 0);
+    assert_eq!(network.nodes().count(), u16::MAX as usize);
+}
@@ -0,0 +1,238 @@
+
+use aoc_common::parse::lines;
+use aoc_common::{AocError, Answer, Result, Solution};
+use num::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use num::{BigInt, BigRational};
+use std::fmt;
+
+/// each line contains a list of numbers
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>> {
+    let mut numbers: Vec<Vec<i64>> = Vec::new();
+    for line in lines(input) {
+        let mut line_numbers: Vec<i64> = Vec::new();
+        for number in line.words() {
+            line_numbers.push(line.number::<i64>(number)?);
+        }
+        numbers.push(line_numbers);
+    }
+    Ok(numbers)
+}
+
+/// integer arithmetic that reports overflow rather than wrapping
+pub trait Backend: Clone + From<i64> + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv {
+    /// how overflow errors name it
+    const NAME: &'static str;
+}
+
+impl Backend for i64 {
+    const NAME: &'static str = "i64";
+}
+
+impl Backend for i128 {
+    const NAME: &'static str = "i128";
+}
+
+impl Backend for BigInt {
+    const NAME: &'static str = "BigInt";
+}
+
+/// a result too large for the backend it was worked out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub backend: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "overflowed {}", self.backend)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl From<Overflow> for AocError {
+    fn from(overflow: Overflow) -> AocError {
+        AocError::Other(overflow.to_string())
+    }
+}
+
+fn checked<N: Backend>(value: Option<N>) -> Result<N, Overflow> {
+    value.ok_or(Overflow { backend: N::NAME })
+}
+
+/// `(-1)^k * value`
+fn signed<N: Backend>(k: usize, value: N) -> Result<N, Overflow> {
+    if k.is_multiple_of(2) { Ok(value) } else { checked(N::from(0).checked_sub(&value)) }
+}
+
+/// `x choose k` for any integer `x`, counting the falling factorial
+/// `x (x - 1) ... (x - k + 1)` over `k!`
+fn binomial<N: Backend>(x: &N, k: usize) -> Result<N, Overflow> {
+    // each partial product of j consecutive integers is divisible by j!
+    let mut acc = N::from(1);
+    for j in 0..k as i64 {
+        let factor = checked(x.checked_sub(&N::from(j)))?;
+        acc = checked(checked(acc.checked_mul(&factor))?.checked_div(&N::from(j + 1)))?;
+    }
+    Ok(acc)
+}
+
+/// the value at position `x` of the polynomial of lowest degree through
+/// `seq`, taking `seq[i]` to be at position `i`. Each value's Lagrange
+/// weight is `(-1)^(n-1-i) * C(x, i) * C(x-i-1, n-1-i)`.
+pub fn value_at<N: Backend>(seq: &[i64], x: i64) -> Result<N, Overflow> {
+    let n = seq.len();
+    let x = N::from(x);
+    let mut sum = N::from(0);
+    for (i, &value) in seq.iter().enumerate() {
+        let rest = checked(x.checked_sub(&N::from(i as i64 + 1)))?;
+        let weight = checked(binomial(&x, i)?.checked_mul(&binomial(&rest, n - 1 - i)?))?;
+        let term = signed(n - 1 - i, checked(weight.checked_mul(&N::from(value)))?)?;
+        sum = checked(sum.checked_add(&term))?;
+    }
+    Ok(sum)
+}
+
+/// the value `k` steps after the end of `seq`, or before its start if `k` is negative
+pub fn extrapolate<N: Backend>(seq: &[i64], k: i64) -> Result<N, Overflow> {
+    if k >= 0 {
+        value_at(seq, seq.len() as i64 - 1 + k)
+    } else {
+        value_at(seq, k)
+    }
+}
+
+/// `extrapolate` in i64, or if that overflows in i128, or else in a BigInt
+pub fn extrapolate_escalating(seq: &[i64], k: i64) -> BigInt {
+    extrapolate::<i64>(seq, k).map(BigInt::from)
+        .or_else(|_| extrapolate::<i128>(seq, k).map(BigInt::from))
+        .or_else(|_| extrapolate::<BigInt>(seq, k))
+        .expect("a BigInt never overflows")
+}
+
+/// the next value: `sum (-1)^(n-1-i) C(n, i) seq[i]`
+pub fn predict<N: Backend>(seq: &[i64]) -> Result<N, Overflow> {
+    extrapolate(seq, 1)
+}
+
+/// the value before the first: `sum (-1)^i C(n, i+1) seq[i]`
+pub fn predict_2<N: Backend>(seq: &[i64]) -> Result<N, Overflow> {
+    extrapolate(seq, -1)
+}
+
+/// the coefficients of the polynomial through `seq`, constant first, as
+/// `seq[0]`'s position is 0. The Newton form `sum d_k x(x-1)...(x-k+1) / k!`,
+/// where `d_k` is the k-th difference at the start, is expanded term by
+/// term over the common denominator `(n-1)!`.
+pub fn coefficients(seq: &[i64]) -> Vec<BigRational> {
+    let n = seq.len();
+    let denominator: BigInt = (1..n).map(BigInt::from).product();
+    let mut numerators = vec![BigInt::from(0); n];
+    // x (x - 1) ... (x - k + 1), constant first
+    let mut falling = vec![BigInt::from(1)];
+    // (n-1)! / k!
+    let mut scale = denominator.clone();
+    for k in 0..n {
+        let difference: BigInt = (0..=k)
+            .map(|i| signed(k - i, binomial(&BigInt::from(k as i64), i)? * seq[i]))
+            .sum::<Result<BigInt, Overflow>>()
+            .expect("a BigInt never overflows");
+        let weight = difference * &scale;
+        for (numerator, term) in numerators.iter_mut().zip(&falling) {
+            *numerator += term * &weight;
+        }
+        // times (x - k)
+        let mut next = vec![BigInt::from(0); falling.len() + 1];
+        for (j, term) in falling.iter().enumerate() {
+            next[j + 1] += term;
+            next[j] -= term * k;
+        }
+        falling = next;
+        if k + 1 < n {
+            scale /= k + 1;
+        }
+    }
+    let mut coefficients: Vec<BigRational> = numerators.into_iter()
+        .map(|numerator| BigRational::new(numerator, denominator.clone()))
+        .collect();
+    while coefficients.len() > 1 && coefficients.last().is_some_and(|c| *c == BigRational::from_integer(0.into())) {
+        coefficients.pop();
+    }
+    coefficients
+}
+
+/// a sum that may not fit an `Answer::Int`
+fn answer(sum: BigInt) -> Answer {
+    i128::try_from(&sum).map_or_else(|_| sum.to_string().into(), Answer::from)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+        parse_input(input)
+    }
+
+    fn part1(seqs: &Vec<Vec<i64>>) -> Result<Answer> {
+        Ok(answer(seqs.iter().map(|seq| extrapolate_escalating(seq, 1)).sum()))
+    }
+
+    fn part2(seqs: &Vec<Vec<i64>>) -> Result<Answer> {
+        Ok(answer(seqs.iter().map(|seq| extrapolate_escalating(seq, -1)).sum()))
+    }
+}
+
+/// the next value found by differencing down to zeros and back up
+#[cfg(test)]
+fn predict_by_differences(numbers: &[i64]) -> i64 {
+    if numbers.iter().all(|x| x == &0) {
+        0
+    } else {
+        let differences: Vec<i64> = numbers.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        predict_by_differences(&differences) + numbers[numbers.len() - 1]
+    }
+}
+
+#[test]
+fn test_closed_form_matches_differences() {
+    let seqs = Day09::parse(include_str!("../puzzle.input")).unwrap();
+    for seq in &seqs {
+        // extend the sequence both ways a step at a time
+        let mut forward = seq.clone();
+        let mut backward: Vec<i64> = seq.iter().rev().copied().collect();
+        for k in 1..=3 {
+            forward.push(predict_by_differences(&forward));
+            backward.push(predict_by_differences(&backward));
+            assert_eq!(extrapolate(seq, k), Ok(*forward.last().unwrap()), "{:?} +{}", seq, k);
+            assert_eq!(extrapolate(seq, -k), Ok(*backward.last().unwrap()), "{:?} -{}", seq, k);
+        }
+        let coefficients = coefficients(seq);
+        for (x, &value) in seq.iter().enumerate() {
+            let fitted = coefficients.iter().rev()
+                .fold(BigRational::from_integer(0.into()), |acc, c| acc * BigInt::from(x) + c);
+            assert_eq!(fitted, BigRational::from_integer(value.into()));
+        }
+    }
+    let seqs = Day09::parse(include_str!("../example.input")).unwrap();
+    let ratios = |cs: &[(i64, i64)]| cs.iter().map(|&(n, d)| BigRational::new(n.into(), d.into())).collect::<Vec<_>>();
+    assert_eq!(coefficients(&seqs[0]), ratios(&[(0, 1), (3, 1)]));
+    assert_eq!(coefficients(&seqs[1]), ratios(&[(1, 1), (3, 2), (1, 2)]));
+}
+
+#[test]
+fn test_overflow_escalates() {
+    let seq = [i64::MAX - 1, i64::MAX];
+    assert_eq!(predict::<i64>(&seq), Err(Overflow { backend: "i64" }));
+    assert_eq!(predict::<i128>(&seq), Ok(i64::MAX as i128 + 1));
+    // the answer would fit, but 2 (m - 1) on the way doesn't
+    assert_eq!(predict_2::<i64>(&seq), Err(Overflow { backend: "i64" }));
+    assert_eq!(extrapolate_escalating(&seq, -1), BigInt::from(i64::MAX - 2));
+    // alternating +-m: the next value is -+m (2^n - 1)
+    let seq: Vec<i64> = (0..100).map(|i| if i % 2 == 0 { i64::MAX } else { -i64::MAX }).collect();
+    assert_eq!(predict::<i128>(&seq), Err(Overflow { backend: "i128" }));
+    let expected = -BigInt::from(i64::MAX) * ((BigInt::from(1) << 100) - 1);
+    assert_eq!(extrapolate_escalating(&seq, 1), expected);
+    assert_eq!(answer(expected.clone()), Answer::Text(expected.to_string()));
+}
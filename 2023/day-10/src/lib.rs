@@ -0,0 +1,126 @@
+
+use aoc_common::grid::{Coord, Dir, Grid};
+use aoc_common::{Answer, Result, Solution};
+use log::trace;
+use std::collections::HashSet;
+
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input, |c| "|-LJ7F.S".contains(c).then_some(c))
+}
+
+/// the directions a tile's pipe leads, 'S' leading anywhere
+fn connections(tile: char) -> &'static [Dir] {
+    match tile {
+        'S' => &Dir::ALL,
+        '|' => &[Dir::North, Dir::South],
+        '-' => &[Dir::East, Dir::West],
+        '7' => &[Dir::South, Dir::West],
+        'L' => &[Dir::North, Dir::East],
+        'F' => &[Dir::South, Dir::East],
+        'J' => &[Dir::North, Dir::West],
+        '.' => &[],
+        _ => unreachable!("parse_input only accepts pipe tiles"),
+    }
+}
+
+fn explore(grid: &Grid<char>, coord: Coord, path: &mut HashSet<Coord>) -> u32 {
+    // the next tile's pipe must lead back here
+    let next_coords = connections(grid[coord]).iter()
+        .filter_map(|&dir| grid.step(coord, dir)
+            .filter(|&next| grid[next] != 'S' && connections(grid[next]).contains(&dir.opposite())))
+        .collect::<Vec<_>>();
+    for next_coord in next_coords {
+        if !path.contains(&next_coord) {
+            path.insert(next_coord);
+            explore(grid, next_coord, path);
+        }
+    }
+    path.len() as u32
+}
+
+/// find interior points
+fn paint(grid: &Grid<char>, path: &HashSet<Coord>) -> HashSet<Coord> {
+    let mut interior = HashSet::new();
+    for (i, row) in grid.rows().enumerate() {
+        let mut inside = false;
+        let mut fwall = false;
+        let mut picture = String::new();
+        for (j, c) in row.iter().enumerate() {
+            let coord = Coord::new(i, j);
+            if path.contains(&coord) {
+                match c {
+                    //'S' => inside = !inside,
+                    '|' => inside = !inside,
+                    '-' => {},
+                    'F' => fwall = true,
+                    '7' => {
+                        if !fwall {
+                            inside = !inside;
+                        }
+                        fwall = false;
+                    },
+                    'L' => fwall = false,
+                    'S' => fwall = false, /* hack based on inspecting puzzle input */
+                    'J' => {
+                        if fwall {
+                            inside = !inside;
+                        }
+                        fwall = false;
+                    },
+                    '.' => {},
+                    _ => unreachable!("parse_input only accepts pipe tiles"),
+                }
+            }
+            if !path.contains(&coord) && inside {
+                interior.insert(coord);
+            }
+            picture.push(
+                if path.contains(&coord) { 
+                    '#' 
+                } else { 
+                    if inside {'I'} else {'.'}
+                });
+        }
+        trace!("{}", picture);
+    }
+    interior
+}
+
+use std::thread;
+
+/// find the tiles of the loop through the start tile
+fn find_loop(grid: &Grid<char>) -> Result<HashSet<Coord>> {
+    let s_coord = grid.iter().find(|(_, &c)| c == 'S').map(|(at, _)| at).ok_or("no start tile 'S'")?;
+
+    let mut path = HashSet::new();
+    path.insert(s_coord);
+
+    // call explore in new thread with big stack
+    thread::scope(|scope| {
+        let explorer = thread::Builder::new().stack_size(32 * 1024 * 1024).spawn_scoped(scope, move || {
+            explore(grid, s_coord, &mut path);
+            path
+        }).map_err(|e| format!("cannot spawn explore thread: {}", e))?;
+        Ok(explorer.join().map_err(|_| "explore panicked")?)
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> Result<Answer> {
+        let num_rooms = find_loop(grid)?.len() as u32;
+        Ok((num_rooms / 2).into())
+    }
+
+    fn part2(grid: &Grid<char>) -> Result<Answer> {
+        let interior = paint(grid, &find_loop(grid)?);
+        Ok(interior.len().into())
+    }
+}
@@ -0,0 +1,99 @@
+
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::{Answer, Result, Solution};
+use log::{debug, trace};
+
+fn parse_input(input: &str) -> Result<Grid<bool>> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// rows and columns without galaxies
+fn empty_lines(grid: &Grid<bool>) -> (Vec<usize>, Vec<usize>) {
+    let rows = (0..grid.height()).filter(|&i| !grid.row(i).contains(&true)).collect();
+    let cols = (0..grid.width()).filter(|&j| !grid.column(j).any(|&galaxy| galaxy)).collect();
+    (rows, cols)
+}
+
+/// return a new grid expanded
+fn expand(grid: &Grid<bool>) -> Grid<bool> {
+    let (empty_rows, empty_cols) = empty_lines(grid);
+    // which old row and column each new one copies, empty ones twice
+    let rows: Vec<usize> = (0..grid.height())
+        .flat_map(|i| if empty_rows.contains(&i) { vec![i, i] } else { vec![i] })
+        .collect();
+    let cols: Vec<usize> = (0..grid.width())
+        .flat_map(|j| if empty_cols.contains(&j) { vec![j, j] } else { vec![j] })
+        .collect();
+    Grid::from_fn(cols.len(), rows.len(), |at| grid[Coord::new(rows[at.row], cols[at.col])])
+}
+
+fn format_grid(grid: &Grid<bool>) -> Grid<char> {
+    grid.map(|&galaxy| if galaxy { '#' } else { '.' })
+}
+
+fn grid_to_coords(grid: &Grid<bool>) -> Vec<Coord> {
+    grid.iter().filter(|(_, &galaxy)| galaxy).map(|(at, _)| at).collect()
+}
+
+fn sum_of_pairwise_distances(coords: &[Coord]) -> usize {
+    let mut sum = 0;
+    for i in 0..coords.len() {
+        for j in i+1..coords.len() {
+            sum += coords[i].manhattan(coords[j]);
+        }
+    }
+    sum
+}
+
+fn sum_of_pairwise_distances2(grid : &Grid<bool>, factor : usize) -> usize {
+    let coords = grid_to_coords(grid);
+    let (rows_to_expand, cols_to_expand) = empty_lines(grid);
+
+    let mut sum = 0;
+    for i in 0..coords.len() {
+        for j in i+1..coords.len() {
+            sum += coords[i].manhattan(coords[j]);
+            for k in &rows_to_expand {
+                if coords[i].row > *k && coords[j].row < *k 
+                    || coords[i].row < *k && coords[j].row > *k {
+                    sum += factor;
+                }
+            }
+            for k in &cols_to_expand {
+                if coords[i].col > *k && coords[j].col < *k 
+                    || coords[i].col < *k && coords[j].col > *k {
+                    sum += factor;
+                }
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>> {
+        let grid = parse_input(input)?;
+        debug!("original grid:\n{}", format_grid(&grid));
+        Ok(grid)
+    }
+
+    fn part1(grid: &Grid<bool>) -> Result<Answer> {
+        let grid = expand(grid);
+        debug!("expanded grid:\n{}", format_grid(&grid));
+        let coords = grid_to_coords(&grid);
+        trace!("coords: {:?}", coords);
+        Ok(sum_of_pairwise_distances(&coords).into())
+    }
+
+    fn part2(grid: &Grid<bool>) -> Result<Answer> {
+        Ok(sum_of_pairwise_distances2(grid, 1000000 - 1).into())
+    }
+}
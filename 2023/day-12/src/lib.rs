@@ -0,0 +1,119 @@
+
+/*
+Input Example:
+
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+*/
+
+use aoc_common::parse::lines;
+use aoc_common::{Answer, Result, Solution};
+use log::debug;
+
+type Record = (Vec<char>, Vec<usize>);
+
+fn parse_input(text: &str) -> Result<Vec<Record>> {
+    let mut input = Vec::new();
+    for line in lines(text) {
+        // split on space
+        // first half --> chars
+        // second half --> nums (split on ',')
+        let mut chars = Vec::new();
+        let mut nums = Vec::new();
+        let mut parts = line.words();
+        let char_part = line.expect(parts.next(), "springs")?;
+        let num_part = line.expect(parts.next(), "group sizes")?;
+        for (i, c) in char_part.char_indices() {
+            if !matches!(c, '.' | '#' | '?') {
+                return Err(line.error_at(&char_part[i..], format!("expected spring, found '{}'", c)));
+            }
+            chars.push(c);
+        }
+        for n in num_part.split(',') {
+            nums.push(line.number::<usize>(n)?);
+        }
+        input.push((chars, nums));
+    }
+    Ok(input)
+}
+
+fn solve(chars: &[char], nums: &[usize]) -> usize {
+    let mut result = 0;
+    if chars.is_empty() {
+        if nums.is_empty() {
+            return 1; //return vec![vec![]]; // single empty solution
+        } else {
+            return 0; // return vec![]; // no solution
+        }
+    }
+    if chars[0] == '.' || chars[0] == '?' {
+        result += solve(&chars[1..], nums);
+    }
+    if chars[0] == '#' || chars[0] == '?' {
+        // we are forced to consume the first num
+        if nums.is_empty() || nums[0] > chars.len() || chars[0..nums[0]].contains(&'.') {
+            // no solution
+        } else if chars.len() == nums[0] {
+            result += solve(&chars[nums[0]..], &nums[1..]);
+        } else if chars[nums[0]] == '#' {
+            // no solution
+        } else {
+            result += solve(&chars[nums[0] + 1..], &nums[1..]);
+        }
+    }
+    result
+}
+
+fn fold(chars: &[char], nums: &[usize]) -> (Vec<char>, Vec<usize>) {
+    let mut new_chars = Vec::new();
+    let mut new_nums = Vec::new();
+    for _i in 0..4 {
+        new_nums.extend_from_slice(nums);
+        new_chars.extend_from_slice(chars);
+        new_chars.push('?');
+    }
+    new_nums.extend_from_slice(nums);
+    new_chars.extend_from_slice(chars);
+    (new_chars, new_nums)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Vec<Record>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Record>) -> Result<Answer> {
+        let mut acc = 0;
+        for (chars, nums) in input {
+            let solutions = solve(chars, nums);
+            debug!("Input: {} {:?}: {} solutions", 
+                chars.iter().collect::<String>(),
+                nums,
+                solutions);
+            acc += solutions;
+        }
+        Ok(acc.into())
+    }
+
+    fn part2(input: &Vec<Record>) -> Result<Answer> {
+        let mut acc = 0;
+        for (chars, nums) in input {
+            let (chars, nums) = fold(chars, nums);
+            let solutions = solve(&chars, &nums);
+            debug!("Input: {} {:?}: {} solutions",
+                chars.into_iter().collect::<String>(),
+                nums,
+                solutions);
+            acc += solutions;
+        }
+        Ok(acc.into())
+    }
+}
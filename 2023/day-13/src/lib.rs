@@ -0,0 +1,121 @@
+
+/*
+EXAMPLE FILE:
+
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+
+*/
+
+use aoc_common::grid::Grid;
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Answer, Result, Solution};
+use log::debug;
+
+/// input contains grids
+/// grids are separated by blank lines
+fn parse_input(input: &str) -> Result<Vec<Grid<bool>>> {
+    let lines: Vec<Line> = lines(input).collect();
+    lines.split(|line| line.text.is_empty())
+        .map(|grid_lines| Grid::from_lines(grid_lines, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+fn parse_line(line: &str) -> Vec<bool> {
+    line.chars().map(|c| c == '#').collect()
+}
+
+fn _print_grid(grid: &Grid<bool>) {
+    debug!("\n{}", grid.map(|&cell| if cell { '#' } else { '.' }));
+}
+
+/** return list of symmetry lines for given row or column vector
+ * [ # .|. # . ] -> [ 2 ]
+ * [ #|#|#|#|# ] -> [ 1, 2, 3, 4 ]
+ * [ #|# . # . ] -> [ 1 ]
+ * [ #|# .|. # ] -> [ 1, 3 ]
+ * [ #|# . #|# ] -> [ 1, 4 ]
+ */
+fn find_vector_symmetries(vec: &[bool], sym: &mut Vec<usize>) {
+    let mut rem_sym = Vec::new();
+    for s in sym.iter() {
+        for i in 0..*s {
+            let j = s + (s - i) - 1;
+            if j >= vec.len() {
+                continue;
+            }
+            if vec[i] != vec[j] {
+                rem_sym.push(*s);
+                break;
+            }
+        }
+    }
+    sym.retain(|&s| !rem_sym.contains(&s));
+}
+
+#[cfg(test)]
+fn assert_find_vector_symmetries(line: &str, expected: &[usize]) {
+    let vec = parse_line(line);
+    let mut sym = (1..vec.len()).collect();
+    eprintln!("{} -> {:?}", line, expected);
+    find_vector_symmetries(&vec, &mut sym);
+    assert_eq!(&sym, expected);
+}
+
+#[test]
+fn test_find_vector_symmetries() {
+    assert_find_vector_symmetries("#..#.", &[2]);
+    assert_find_vector_symmetries("#####", &[1, 2, 3, 4]);
+    assert_find_vector_symmetries("##.#.", &[1]);
+    assert_find_vector_symmetries("##..#", &[1, 3]);
+    assert_find_vector_symmetries("##.##", &[1, 4]);
+}
+
+/// return list of vertical and horizontal symmetry lines for given grid
+fn find_grid_symmetries(grid: &Grid<bool>) -> (Vec<usize>, Vec<usize>) {
+    let mut sym_v = (1..grid.width()).collect();
+    let mut sym_h = (1..grid.height()).collect();
+    for row in grid.rows() {
+        find_vector_symmetries(row, &mut sym_v);
+    }
+    for col in grid.columns() {
+        find_vector_symmetries(&col.copied().collect::<Vec<_>>(), &mut sym_h);
+    }
+    (sym_v, sym_h)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<bool>>;
+
+    fn parse(input: &str) -> Result<Vec<Grid<bool>>> {
+        parse_input(input)
+    }
+
+    fn part1(grids: &Vec<Grid<bool>>) -> Result<Answer> {
+        Ok(grids.iter()
+            .map(find_grid_symmetries)
+            .map(|(sym_v, sym_h)| -> usize { sym_v.iter().sum::<usize>() + 100 * sym_h.iter().sum::<usize>() })
+            .sum::<usize>()
+            .into())
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::parse::{lines, Line};
+use aoc_common::{AocError, Answer, Result, Solution};
+use log::trace;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+fn hash(input: &str) -> i32 {
+    input.as_bytes().to_vec().iter()
+        .fold(0, |acc, &x| 
+            (acc + x as i32) * 17 % 256)
+}
+
+#[derive(Debug)]
+enum Operation {
+    /// put in a lens of this focal length
+    Add(i32),
+    Remove,
+}
+
+pub struct OpCode {
+    label: String,
+    operation: Operation,
+}
+
+impl OpCode {
+    /// parse one step of the initialization sequence, a slice of `line`
+    fn parse(line: &Line, opcode_str: &str) -> Result<OpCode> {
+        static RE: Lazy<Regex> = Lazy::new(|| 
+            Regex::new(r"^([a-z]+)(?:(-)|=([0-9]))$").expect("valid regex"));
+        let caps = RE.captures(opcode_str)
+            .ok_or_else(|| line.error_at(opcode_str, format!("expected label followed by '-' or '=N', found '{}'", opcode_str)))?;
+        let label = caps[1].to_string();
+        let operation = match caps.get(3) {
+            Some(x) => Operation::Add(line.number::<i32>(&opcode_str[x.range()])?),
+            None => Operation::Remove,
+        };
+        Ok(OpCode {
+            label,
+            operation,
+        })
+    }
+}
+
+impl std::fmt::Display for OpCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.operation {
+            Operation::Add(focal_length) => write!(f, "{}={}", self.label, focal_length),
+            Operation::Remove => write!(f, "{}-", self.label),
+        }
+    }
+}
+
+struct Lens {
+    label: String,
+    focal_length: i32,
+}
+
+struct State {
+    boxes: [Vec<Lens>; 256],
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            boxes: [(); 256].map(|_| Vec::new()),
+        }
+    }
+
+    fn exec(&mut self, opcode: &OpCode) {
+        let mybox = &mut self.boxes[hash(&opcode.label) as usize];
+        match opcode.operation {
+            Operation::Add(focal_length) => {
+                for lens in mybox.iter_mut() {
+                    if lens.label == opcode.label {
+                        lens.focal_length = focal_length;
+                        return;
+                    }
+                }
+                mybox.push(Lens {
+                    label: opcode.label.clone(),
+                    focal_length,
+                });
+            },
+            Operation::Remove => {
+                mybox.retain(|x| x.label != opcode.label);
+            },
+        }
+    }
+
+    fn print(&self) {
+        for (i, mybox) in self.boxes.iter().enumerate() {
+            if !mybox.is_empty() {
+                trace!("Box {}: {}", i, 
+                    mybox.iter().map(|x| format!("[{} {}]", x.label, x.focal_length))
+                        .collect::<Vec<String>>().join(" "));
+            }
+        }
+    }
+
+    fn focusing_power(&self) -> i32 {
+        self.boxes.iter().enumerate()
+            .map(|(i, mybox)| {
+                mybox.iter().enumerate().map(|(j, x)| {
+                    (i + 1) as i32 * (j + 1) as i32 * x.focal_length
+                }).sum::<i32>()
+            }).sum::<i32>()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    /// each step as written, for hashing, and as parsed
+    type Input = Vec<(String, OpCode)>;
+
+    fn parse(input: &str) -> Result<Vec<(String, OpCode)>> {
+        let line = lines(input).next().ok_or_else(|| AocError::parse(1, 1, "empty input"))?;
+        line.text.split(',')
+            .map(|step| Ok((step.to_string(), OpCode::parse(&line, step)?)))
+            .collect()
+    }
+
+    fn part1(steps: &Vec<(String, OpCode)>) -> Result<Answer> {
+        let result = steps.iter()
+            .map(|(step, _)| hash(step))
+            .sum::<i32>();
+        Ok(result.into())
+    }
+
+    fn part2(steps: &Vec<(String, OpCode)>) -> Result<Answer> {
+        let mut state = State::new();
+        for (_, x) in steps {
+            trace!("After \"{}\":", x);
+            state.exec(x);
+            state.print();
+        }
+        Ok(state.focusing_power().into())
+    }
+}
+
+#[test]
+fn test_malformed_steps_are_rejected() {
+    assert_eq!(Day15::parse("rn=1,cm-").unwrap()[0].1.to_string(), "rn=1");
+    for bad in ["rn=,cm-", "rn=1,cm-5", "rn=12"] {
+        let err = Day15::parse(bad).err().unwrap().to_string();
+        assert!(err.contains("expected label followed by '-' or '=N'"), "{}: {}", bad, err);
+    }
+}
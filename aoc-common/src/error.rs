@@ -0,0 +1,72 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// everything that can go wrong while loading, parsing or solving a puzzle
+#[derive(Debug)]
+pub enum AocError {
+    /// the input text is malformed at a known position
+    Parse {
+        file: Option<PathBuf>,
+        /// 1-based line number
+        line: usize,
+        /// 1-based column, counted in characters
+        column: usize,
+        message: String,
+    },
+    /// an input file could not be read
+    Io { path: PathBuf, source: io::Error },
+    /// anything else, e.g. an input that has no answer
+    Other(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> AocError {
+        AocError::Parse { file: None, line, column, message: message.into() }
+    }
+
+    /// name the file that a parse error refers to
+    pub fn in_file(self, path: &Path) -> AocError {
+        match self {
+            AocError::Parse { file: None, line, column, message } =>
+                AocError::Parse { file: Some(path.to_path_buf()), line, column, message },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse { file, line, column, message } => {
+                if let Some(file) = file {
+                    write!(f, "{}:", file.display())?;
+                }
+                write!(f, "{}:{}: {}", line, column, message)
+            },
+            AocError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            AocError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for AocError {
+    fn from(message: String) -> AocError {
+        AocError::Other(message)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(message: &str) -> AocError {
+        AocError::Other(message.to_string())
+    }
+}
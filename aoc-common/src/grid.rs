@@ -0,0 +1,250 @@
+//! A rectangular grid of cells, as found in many puzzle inputs.
+
+use crate::parse::{lines, Line};
+use crate::Result;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// a cell's position: row then column, counted from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub const fn new(row: usize, col: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    /// the coordinate `rows` down and `cols` right, unless that is above
+    /// or left of the grid
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Coord> {
+        Some(Coord {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+
+    pub fn step(self, dir: Dir) -> Option<Coord> {
+        let (rows, cols) = dir.offset();
+        self.offset(rows, cols)
+    }
+
+    /// distance in steps between grid cells
+    pub fn manhattan(self, other: Coord) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+/// a direction on the grid, north being up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    /// (rows, columns) moved by one step
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir::North => (-1, 0),
+            Dir::East => (0, 1),
+            Dir::South => (1, 0),
+            Dir::West => (0, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+}
+
+/// (rows, columns) to each of the 8 surrounding cells
+const AROUND: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// cells stored row by row in one `Vec`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Coord) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| Coord { row, col }))
+            .map(&mut cell)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// parse one cell per character, `cell` returning `None` for
+    /// characters that don't belong in the grid
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        Grid::from_lines(&lines(input).collect::<Vec<_>>(), cell)
+    }
+
+    /// like `parse`, for a grid that is only part of the input
+    pub fn from_lines(lines: &[Line], mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let width = lines.first().map_or(0, |line| line.text.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            for (i, c) in line.text.char_indices() {
+                cells.push(cell(c)
+                    .ok_or_else(|| line.error_at(&line.text[i..], format!("unexpected character '{}'", c)))?);
+            }
+            let found = line.end_column() - 1;
+            if found != width {
+                return Err(line.error_at_end(format!("expected {} columns, found {}", width, found)));
+            }
+        }
+        Ok(Grid { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, at: Coord) -> bool {
+        at.row < self.height && at.col < self.width
+    }
+
+    pub fn get(&self, at: Coord) -> Option<&T> {
+        self.contains(at).then(|| &self.cells[at.row * self.width + at.col])
+    }
+
+    pub fn get_mut(&mut self, at: Coord) -> Option<&mut T> {
+        self.contains(at).then(|| &mut self.cells[at.row * self.width + at.col])
+    }
+
+    /// every coordinate, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Coord { row, col }))
+    }
+
+    /// every cell with its coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.cells)
+    }
+
+    /// the neighbor one step in `dir`, if it is inside the grid
+    pub fn step(&self, at: Coord, dir: Dir) -> Option<Coord> {
+        at.step(dir).filter(|&next| self.contains(next))
+    }
+
+    /// the up to 4 orthogonal neighbors inside the grid
+    pub fn neighbors4(&self, at: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Dir::ALL.into_iter().filter_map(move |dir| self.step(at, dir))
+    }
+
+    /// the up to 8 neighbors inside the grid, diagonals included
+    pub fn neighbors8(&self, at: Coord) -> impl Iterator<Item = Coord> + '_ {
+        AROUND.into_iter()
+            .filter_map(move |(rows, cols)| at.offset(rows, cols))
+            .filter(|&next| self.contains(next))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks(0) panics, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is outside a {}x{} grid", col, self.width, self.height);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(&mut f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// swap rows and columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |at| self[Coord::new(at.col, at.row)].clone())
+    }
+
+    /// turn a quarter clockwise
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |at| self[Coord::new(self.height - 1 - at.col, at.row)].clone())
+    }
+
+    /// turn a quarter anticlockwise
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |at| self[Coord::new(at.col, self.width - 1 - at.row)].clone())
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: Coord) -> &T {
+        assert!(self.contains(at), "{:?} is outside a {}x{} grid", at, self.width, self.height);
+        &self.cells[at.row * self.width + at.col]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, at: Coord) -> &mut T {
+        assert!(self.contains(at), "{:?} is outside a {}x{} grid", at, self.width, self.height);
+        &mut self.cells[at.row * self.width + at.col]
+    }
+}
+
+/// one line per row, as in the puzzle input
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid_views_and_turns() {
+    let grid = Grid::parse("abc\ndef\n", Some).unwrap();
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert!(std::panic::catch_unwind(|| grid.column(3).count()).is_err());
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_left().rotate_right(), grid);
+    assert_eq!(grid.neighbors4(Coord::new(0, 0)).collect::<Vec<_>>(), [Coord::new(0, 1), Coord::new(1, 0)]);
+    assert_eq!(grid.neighbors8(Coord::new(1, 1)).count(), 5);
+
+    let err = Grid::parse("abc\nde\n", Some).unwrap_err();
+    assert_eq!(err.to_string(), "2:3: expected 3 columns, found 2");
+}
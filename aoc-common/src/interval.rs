@@ -0,0 +1,177 @@
+//! Sets of integers stored as sorted, disjoint ranges.
+
+use std::iter::Sum;
+use std::ops::{Range, Sub};
+
+/// a set of values kept as half-open ranges that are sorted, non-empty,
+/// and never overlap or touch: inserting `3..5` into `{1..3}` gives `{1..5}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = u64> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    /// sort and coalesce ranges that may overlap
+    fn normalize(mut ranges: Vec<Range<T>>) -> IntervalSet<T> {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+        let mut joined: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match joined.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => joined.push(range),
+            }
+        }
+        IntervalSet { ranges: joined }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        // the ranges that overlap or touch the new one are replaced by their union
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let (mut start, mut end) = (range.start, range.end);
+        if first < last {
+            start = start.min(self.ranges[first].start);
+            end = end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [start..end]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// number of separate ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::normalize(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // move past whichever range ends first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // skip the ranges of other that end before this one starts
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                if start < other.ranges[k].start {
+                    ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum> IntervalSet<T> {
+    /// how many values the set holds
+    pub fn total_len(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> IntervalSet<T> {
+        IntervalSet::normalize(ranges.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<T: Copy + Ord> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[test]
+fn test_interval_set_algebra() {
+    let a: IntervalSet = [5..8, 0..3, 2..4, 8..10].into_iter().collect();
+    assert_eq!(a.ranges(), [0..4, 5..10]);
+    let b: IntervalSet = [3..6, 9..12].into_iter().collect();
+    assert_eq!((a.union(&b).min(), a.union(&b).range_count()), (Some(0), 1));
+    assert_eq!(a.union(&b).total_len(), 12);
+    assert_eq!(a.intersection(&b).ranges(), [3..4, 5..6, 9..10]);
+    assert_eq!(a.difference(&b).ranges(), [0..3, 6..9]);
+    assert_eq!(b.difference(&a).ranges(), [4..5, 10..12]);
+    assert_eq!(a.total_len(), 9);
+    assert!(a.contains(9) && !a.contains(4) && !a.contains(10));
+
+    let mut c = a.clone();
+    c.insert(4..5);
+    assert_eq!((c.min(), c.total_len(), c.range_count()), (Some(0), 10, 1));
+    c.insert(12..13);
+    c.insert(20..20);
+    assert_eq!(c.ranges(), [0..10, 12..13]);
+}
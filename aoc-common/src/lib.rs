@@ -0,0 +1,266 @@
+//! Shared plumbing for the Advent of Code solutions.
+//!
+//! Every day implements [`Solution`]: parse the input text once, then
+//! answer each part from the parsed form. This crate owns the rest of the
+//! boilerplate (loading the input, reporting errors, printing answers) so
+//! the day crates only contain the puzzle logic.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+mod error;
+pub mod grid;
+pub mod interval;
+pub mod parse;
+
+pub use error::AocError;
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+/// which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, not '{}'", s)),
+        }
+    }
+}
+
+/// the answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// the day has no solver for this part yet
+    Unsolved,
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// a day's puzzle: parse the input once, then answer each part from it
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// solve one part from already-parsed input
+pub fn solve_part<S: Solution>(input: &S::Input, part: Part) -> Result<Answer> {
+    match part {
+        Part::One => S::part1(input),
+        Part::Two => S::part2(input),
+    }
+}
+
+/// parse the input text and solve one part
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer> {
+    solve_part::<S>(&S::parse(input)?, part)
+}
+
+/// a stage of solving a puzzle that is timed on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Phase, String> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            _ => s.strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Phase::Part)
+                .ok_or_else(|| format!("unknown phase '{}'", s)),
+        }
+    }
+}
+
+/// the time of every run of each phase
+pub type Timings = Vec<(Phase, Vec<Duration>)>;
+
+/// time `runs` runs each of parsing and solving `parts`, after one
+/// untimed warm-up run of each
+pub fn time_phases<S: Solution>(input: &str, parts: &[Part], runs: usize) -> Result<Timings> {
+    let mut timings = vec![(Phase::Parse, time_runs(runs, || S::parse(input))?)];
+    let parsed = S::parse(input)?;
+    for &part in parts {
+        timings.push((Phase::Part(part), time_runs(runs, || solve_part::<S>(&parsed, part))?));
+    }
+    Ok(timings)
+}
+
+fn time_runs<T>(runs: usize, mut run: impl FnMut() -> Result<T>) -> Result<Vec<Duration>> {
+    black_box(run()?);
+    (0..runs).map(|_| {
+        let start = Instant::now();
+        let output = run()?;
+        let elapsed = start.elapsed();
+        // dropped after the clock stops, so freeing it isn't timed
+        black_box(output);
+        Ok(elapsed)
+    }).collect()
+}
+
+/// a solution registered for one puzzle, with its input type erased
+/// so that every day can live in the same table
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str, Part) -> Result<Answer>,
+    pub time: fn(&str, &[Part], usize) -> Result<Timings>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Day {
+        Day { year, day, solve: solve::<S>, time: time_phases::<S> }
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        day_dir(self.year, self.day)
+    }
+}
+
+/// directory holding a day's crate and input files, relative to the repo root
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(&year.to_string()).join(format!("day-{:02}", day))
+}
+
+/// path of the input file given as the first command-line argument
+pub fn input_path() -> Result<PathBuf> {
+    env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .ok_or_else(|| "usage: <binary> <input-file>".into())
+}
+
+/// read a whole input file
+pub fn read_input(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| AocError::Io { path: path.to_path_buf(), source })
+}
+
+/// print the answer to one part of the puzzle
+pub fn print_answer(part: Part, answer: &Answer) {
+    println!("Part {}: {}", part, answer);
+}
+
+/// send the days' `log` output to stderr. Nothing below a warning is
+/// shown by default; each `verbosity` step (`-v`, `-vv`) shows one more
+/// level, and `RUST_LOG` can override it per day, e.g. `RUST_LOG=day_05=trace`.
+pub fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => log::LevelFilter::Warn,
+        1 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
+
+/// run a day's main function, reporting errors without a backtrace
+pub fn run(main: impl FnOnce() -> Result<()>) -> ExitCode {
+    match main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// entry point for a day's own binary: solve both parts of the
+/// input file named on the command line
+pub fn main<S: Solution>() -> ExitCode {
+    init_logging(0);
+    run(|| {
+        let path = input_path()?;
+        let input = S::parse(&read_input(&path)?).map_err(|e| e.in_file(&path))?;
+        for part in Part::ALL {
+            match solve_part::<S>(&input, part) {
+                Ok(answer) => print_answer(part, &answer),
+                Err(e) => eprintln!("Error - Part {}: {}", part, e.in_file(&path)),
+            }
+        }
+        Ok(())
+    })
+}
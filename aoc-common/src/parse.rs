@@ -0,0 +1,104 @@
+//! Helpers for parsers that report where the input went wrong.
+
+use crate::{AocError, Result};
+use std::str::{FromStr, SplitWhitespace};
+
+/// one line of puzzle input, remembering where it came from so that
+/// errors can point at the offending character
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based line number
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// the lines of an input, numbered from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line { number: i + 1, text })
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column where `part`, a slice of this line, starts
+    pub fn column_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize)
+            .saturating_sub(self.text.as_ptr() as usize)
+            .min(self.text.len());
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// column just past the last character
+    pub fn end_column(&self) -> usize {
+        self.text.chars().count() + 1
+    }
+
+    pub fn error(&self, column: usize, message: impl Into<String>) -> AocError {
+        AocError::parse(self.number, column, message)
+    }
+
+    /// an error pointing at the start of `part`, a slice of this line
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> AocError {
+        self.error(self.column_of(part), message)
+    }
+
+    /// an error pointing just past the end of the line
+    pub fn error_at_end(&self, message: impl Into<String>) -> AocError {
+        self.error(self.end_column(), message)
+    }
+
+    pub fn words(&self) -> SplitWhitespace<'a> {
+        self.text.split_whitespace()
+    }
+
+    /// unwrap a token that must be present, e.g. the next of `words()`
+    pub fn expect(&self, token: Option<&'a str>, what: &str) -> Result<&'a str> {
+        token.ok_or_else(|| self.error_at_end(format!("expected {}, found end of line", what)))
+    }
+
+    /// strip a fixed prefix such as `"Game "` from the start of `part`
+    pub fn expect_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error_at(part, format!("expected '{}'", prefix.trim())))
+    }
+
+    /// parse a number from `token`, a slice of this line
+    pub fn number<T>(&self, token: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        token.parse().map_err(|e: T::Err| {
+            let digits = token.strip_prefix(['-', '+']).unwrap_or(token);
+            match digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                Some((i, c)) => self.error_at(&digits[i..], format!("expected digit, found '{}'", c)),
+                None if digits.is_empty() => self.error_at(token, "expected number"),
+                None => self.error_at(token, format!("invalid number '{}': {}", token, e)),
+            }
+        })
+    }
+
+    /// parse a whitespace-separated list of numbers from `part`, a slice of this line
+    pub fn numbers<T>(&self, part: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        part.split_whitespace().map(|token| self.number(token)).collect()
+    }
+
+    /// check that every character of the line is one of `allowed`
+    pub fn expect_chars(&self, allowed: &str) -> Result<()> {
+        match self.text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            Some((i, c)) => Err(self.error_at(&self.text[i..], format!("unexpected character '{}'", c))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn test_number_error_points_at_bad_digit() {
+    let line = lines("ab\n52 5x 48").nth(1).unwrap();
+    let token = line.words().nth(1).unwrap();
+    let err = line.number::<u64>(token).unwrap_err();
+    assert_eq!(err.to_string(), "2:5: expected digit, found 'x'");
+    assert_eq!(err.in_file("puzzle.input".as_ref()).to_string(), "puzzle.input:2:5: expected digit, found 'x'");
+}
@@ -0,0 +1,56 @@
+//! Expected answers recorded in each day's `answers.toml`.
+//!
+//! The manifest has one table per input file in the day's directory,
+//! holding `part1` and/or `part2`. A file whose parts are not solved yet
+//! still gets an (empty) table so that every input is accounted for.
+//!
+//! ```toml
+//! ["example.input"]
+//! part1 = 142
+//! part2 = 142
+//! ```
+
+use aoc_common::{AocError, Answer, Part, Result};
+use std::fs;
+use std::path::Path;
+
+pub const MANIFEST: &str = "answers.toml";
+
+/// the answers recorded for one input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub file: String,
+    pub parts: Vec<(Part, Answer)>,
+}
+
+/// read the expected answers from the manifest in `dir`
+pub fn load(dir: &Path) -> Result<Vec<Answers>> {
+    let path = dir.join(MANIFEST);
+    let text = fs::read_to_string(&path)
+        .map_err(|source| AocError::Io { path: path.clone(), source })?;
+    parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+}
+
+fn parse(text: &str) -> Result<Vec<Answers>, String> {
+    let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+    table.into_iter().map(|(file, parts)| {
+        let parts = parts.as_table()
+            .ok_or_else(|| format!("[\"{}\"] should be a table of answers", file))?
+            .iter()
+            .map(|(key, value)| {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("[\"{}\"] has unknown key '{}'", file, key)),
+                };
+                let answer = match value {
+                    toml::Value::Integer(n) => Answer::Int(*n as i128),
+                    toml::Value::String(s) => Answer::Text(s.clone()),
+                    _ => return Err(format!("[\"{}\"] {} should be a number or string", file, key)),
+                };
+                Ok((part, answer))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Answers { file, parts })
+    }).collect()
+}
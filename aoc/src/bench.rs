@@ -0,0 +1,137 @@
+//! Timing every phase of each day, with a history to spot regressions.
+//!
+//! Each `aoc bench` appends one entry to a JSON history file. A phase is
+//! flagged when its median is slower than in the latest earlier entry by
+//! more than the given threshold.
+
+use aoc_common::{AocError, Phase, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// timing statistics for one phase of one day, in nanoseconds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`
+    pub phase: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub p10_ns: u64,
+    pub median_ns: u64,
+    pub p90_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn new(year: u16, day: u8, phase: Phase, times: &[Duration]) -> Stats {
+        let mut ns: Vec<u64> = times.iter().map(|t| t.as_nanos() as u64).collect();
+        ns.sort_unstable();
+        Stats {
+            year,
+            day,
+            phase: phase.to_string(),
+            runs: ns.len(),
+            min_ns: percentile(&ns, 0.0),
+            p10_ns: percentile(&ns, 10.0),
+            median_ns: percentile(&ns, 50.0),
+            p90_ns: percentile(&ns, 90.0),
+            max_ns: percentile(&ns, 100.0),
+        }
+    }
+
+    fn is_same_phase(&self, other: &Stats) -> bool {
+        (self.year, self.day, &self.phase) == (other.year, other.day, &other.phase)
+    }
+}
+
+/// nearest-rank percentile of sorted samples
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// one `aoc bench` run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// unix time the run finished
+    pub time: u64,
+    pub results: Vec<Stats>,
+}
+
+/// a phase that got slower since the previous run that timed it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub stats: Stats,
+    pub previous_median_ns: u64,
+}
+
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// load the history file; a missing file is an empty history
+    pub fn load(path: &Path) -> Result<History> {
+        let entries = match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(source) => return Err(AocError::Io { path: path.to_path_buf(), source }),
+        };
+        Ok(History { path: path.to_path_buf(), entries })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// the most recent earlier timing of the same phase
+    pub fn previous(&self, stats: &Stats) -> Option<&Stats> {
+        self.entries.iter().rev()
+            .find_map(|entry| entry.results.iter().find(|s| s.is_same_phase(stats)))
+    }
+
+    /// phases whose median grew by more than `threshold` percent. Phases
+    /// that moved by under a microsecond are left alone, as that is
+    /// within the noise for the fastest days.
+    pub fn regressions(&self, results: &[Stats], threshold: f64) -> Vec<Regression> {
+        results.iter().filter_map(|stats| {
+            let previous = self.previous(stats)?.median_ns;
+            let slower = stats.median_ns as f64 > previous as f64 * (1.0 + threshold / 100.0)
+                && stats.median_ns > previous + 1000;
+            slower.then(|| Regression { stats: stats.clone(), previous_median_ns: previous })
+        }).collect()
+    }
+
+    /// append an entry and write the whole history back
+    pub fn push(&mut self, entry: Entry) -> Result<()> {
+        self.entries.push(entry);
+        let json = serde_json::to_string_pretty(&self.entries).map_err(|e| e.to_string())?;
+        fs::write(&self.path, json + "\n").map_err(|source| AocError::Io { path: self.path.clone(), source })
+    }
+}
+
+#[test]
+fn test_regressions_compare_with_latest_timing() {
+    let times = |ms: &[u64]| ms.iter().map(|&ms| Duration::from_millis(ms)).collect::<Vec<_>>();
+    let stats = Stats::new(2023, 11, Phase::Part(aoc_common::Part::Two), &times(&[9, 1, 3, 2, 4]));
+    assert_eq!((stats.min_ns, stats.median_ns, stats.max_ns), (1_000_000, 3_000_000, 9_000_000));
+
+    let history = History {
+        path: PathBuf::new(),
+        entries: vec![
+            Entry { time: 1, results: vec![Stats { median_ns: 1_000_000, ..stats.clone() }] },
+            Entry { time: 2, results: vec![Stats { median_ns: 2_600_000, ..stats.clone() }] },
+        ],
+    };
+    let regressions = history.regressions(std::slice::from_ref(&stats), 10.0);
+    assert_eq!(regressions, vec![Regression { stats: stats.clone(), previous_median_ns: 2_600_000 }]);
+    assert!(history.regressions(&[stats], 20.0).is_empty());
+}
@@ -0,0 +1,33 @@
+//! Table of every solved day, so that any puzzle can be solved by
+//! year and day number instead of by running its own binary.
+
+use aoc_common::{Day, Result};
+
+pub mod answers;
+pub mod bench;
+pub mod site;
+pub mod submit;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_01::Day01>(2023, 1),
+    Day::new::<day_02::Day02>(2023, 2),
+    Day::new::<day_03::Day03>(2023, 3),
+    Day::new::<day_04::Day04>(2023, 4),
+    Day::new::<day_05::Day05>(2023, 5),
+    Day::new::<day_06::Day06>(2023, 6),
+    Day::new::<day_07::Day07>(2023, 7),
+    Day::new::<day_08::Day08>(2023, 8),
+    Day::new::<day_09::Day09>(2023, 9),
+    Day::new::<day_10::Day10>(2023, 10),
+    Day::new::<day_11::Day11>(2023, 11),
+    Day::new::<day_12::Day12>(2023, 12),
+    Day::new::<day_13::Day13>(2023, 13),
+    Day::new::<day_15::Day15>(2023, 15),
+];
+
+/// look up the solution for one puzzle
+pub fn find(year: u16, day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.day == day)
+        .ok_or_else(|| format!("no solution for {} day {}", year, day).into())
+}
@@ -0,0 +1,216 @@
+use aoc::answers;
+use aoc::bench::{Entry, History, Stats};
+use aoc::site::{self, Fetched, Site};
+use aoc::submit::{self, Ledger, Verdict};
+use aoc_common::{day_dir, init_logging, print_answer, read_input, run, Answer, Part, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// show the days' debug output (-v), or trace it step by step (-vv)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// solve one day's puzzle
+    Run {
+        year: u16,
+        day: u8,
+        /// solve only this part (default: both)
+        #[arg(long)]
+        part: Option<Part>,
+        /// input file (default: <year>/day-<NN>/puzzle.input)
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// download a day's example and puzzle input, unless already present
+    Fetch {
+        year: u16,
+        day: u8,
+    },
+    /// solve one part of a puzzle and submit the answer
+    Submit {
+        day: u8,
+        part: Part,
+        /// default: the latest year with solutions
+        #[arg(long)]
+        year: Option<u16>,
+        /// input file (default: <year>/day-<NN>/puzzle.input)
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// time every phase of each day's puzzle input, or of only one year or day
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// timed runs of each phase
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        #[arg(long, default_value = "bench-history.json")]
+        history: PathBuf,
+        /// flag phases whose median grew by more than this many percent
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Part 1: <answer>`
+    Text,
+    /// one JSON record per part and line
+    Json,
+}
+
+/// the answer to one part, as printed by `--format json`
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// a number, a string, or null if the part is unsolved
+    answer: serde_json::Value,
+    /// parsing and solving, in nanoseconds
+    elapsed_ns: u64,
+    input_sha256: String,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    run(|| match cli.command {
+        Command::Run { year, day, part, input, format } => run_day(year, day, part, input, format),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit { day, part, year, input } => submit(year, day, part, input),
+        Command::Bench { year, day, runs, history, threshold } => bench(year, day, runs, &history, threshold),
+    })
+}
+
+fn run_day(year: u16, day: u8, part: Option<Part>, input: Option<PathBuf>, format: Format) -> Result<()> {
+    let day = aoc::find(year, day)?;
+    let path = input.unwrap_or_else(|| day.dir().join("puzzle.input"));
+    let text = read_input(&path)?;
+    let input_sha256 = format!("{:x}", Sha256::digest(&text));
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    for part in parts {
+        let start = Instant::now();
+        let answer = (day.solve)(&text, part).map_err(|e| e.in_file(&path))?;
+        let elapsed = start.elapsed();
+        match format {
+            Format::Text => print_answer(part, &answer),
+            Format::Json => {
+                let record = Record {
+                    year: day.year,
+                    day: day.day,
+                    part: part.number(),
+                    answer: answer_json(&answer),
+                    elapsed_ns: elapsed.as_nanos() as u64,
+                    input_sha256: input_sha256.clone(),
+                };
+                println!("{}", serde_json::to_string(&record).map_err(|e| e.to_string())?);
+            },
+        }
+    }
+    Ok(())
+}
+
+fn answer_json(answer: &Answer) -> serde_json::Value {
+    match answer {
+        Answer::Int(n) => serde_json::Number::from_i128(*n)
+            .map_or_else(|| n.to_string().into(), serde_json::Value::Number),
+        Answer::Text(s) => s.as_str().into(),
+        Answer::Unsolved => serde_json::Value::Null,
+    }
+}
+
+fn fetch(year: u16, day: u8) -> Result<()> {
+    let site = Site::from_env()?;
+    for fetched in site::fetch(&site, year, day, &day_dir(year, day))? {
+        match fetched {
+            Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+            Fetched::Cached(path) => println!("cached {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(year: Option<u16>, day: u8, part: Part, input: Option<PathBuf>) -> Result<()> {
+    let year = year.or_else(|| aoc::DAYS.iter().map(|d| d.year).max()).ok_or("no solutions yet")?;
+    let day = aoc::find(year, day)?;
+    let path = input.unwrap_or_else(|| day.dir().join("puzzle.input"));
+    let answer = (day.solve)(&read_input(&path)?, part).map_err(|e| e.in_file(&path))?;
+    print_answer(part, &answer);
+
+    let site = Site::from_env()?;
+    let mut ledger = Ledger::load(&day.dir())?;
+    let judgement = submit::submit(&site, year, day.day, part, &answer, &mut ledger, unix_now()?)?;
+    match judgement.wait {
+        Some(wait) => println!("{} (wait {}s before the next submission)", judgement.verdict, wait),
+        None => println!("{}", judgement.verdict),
+    }
+    if judgement.verdict == Verdict::Correct {
+        println!("remember to record it in {}", day.dir().join(aoc::answers::MANIFEST).display());
+    }
+    Ok(())
+}
+
+fn bench(year: Option<u16>, day: Option<u8>, runs: usize, history: &Path, threshold: f64) -> Result<()> {
+    let mut history = History::load(history)?;
+    let mut results = Vec::new();
+    let days = aoc::DAYS.iter()
+        .filter(|d| year.is_none_or(|year| d.year == year) && day.is_none_or(|day| d.day == day));
+    for day in days {
+        // only parts with a recorded answer are timed, which leaves out
+        // the ones that are known to be too slow to run
+        let path = day.dir().join("puzzle.input");
+        let parts: Vec<Part> = answers::load(&day.dir())?.into_iter()
+            .filter(|answers| answers.file == "puzzle.input")
+            .flat_map(|answers| answers.parts.into_iter().map(|(part, _)| part))
+            .collect();
+        let text = read_input(&path)?;
+        for (phase, times) in (day.time)(&text, &parts, runs).map_err(|e| e.in_file(&path))? {
+            let stats = Stats::new(day.year, day.day, phase, &times);
+            let change = match history.previous(&stats) {
+                Some(previous) => format!("{:+.1}%", (stats.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0),
+                None => "new".to_string(),
+            };
+            println!("{} day {:2} {:6} median {:>10.2?}  p10 {:>10.2?}  p90 {:>10.2?}  {}",
+                day.year, day.day, phase, ns(stats.median_ns), ns(stats.p10_ns), ns(stats.p90_ns), change);
+            results.push(stats);
+        }
+    }
+
+    let regressions = history.regressions(&results, threshold);
+    history.push(Entry { time: unix_now()?, results })?;
+    for r in &regressions {
+        eprintln!("regression: {} day {} {} median {:.2?} -> {:.2?}",
+            r.stats.year, r.stats.day, r.stats.phase, ns(r.previous_median_ns), ns(r.stats.median_ns));
+    }
+    match regressions.len() {
+        0 => Ok(()),
+        n => Err(format!("{} phases are more than {}% slower than last time", n, threshold).into()),
+    }
+}
+
+fn ns(ns: u64) -> Duration {
+    Duration::from_nanos(ns)
+}
+
+fn unix_now() -> Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH).map_err(|e| e.to_string())?.as_secs())
+}
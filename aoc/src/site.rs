@@ -0,0 +1,161 @@
+//! Talking to the Advent of Code website.
+//!
+//! The base URL and session token come from the environment so that tests
+//! and CI can point the runner at a local stand-in instead of the real site:
+//!
+//! - `AOC_BASE_URL` (default `https://adventofcode.com`)
+//! - `AOC_SESSION`, or failing that the contents of `.aoc-session` in the
+//!   current directory: the value of the site's `session` cookie
+
+use aoc_common::{AocError, Part, Result};
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct Site {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: Option<String>) -> Site {
+        Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// configure from `AOC_BASE_URL` and `AOC_SESSION` / `.aoc-session`
+    pub fn from_env() -> Result<Site> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => Some(session),
+            Err(_) => match fs::read_to_string(SESSION_FILE) {
+                Ok(session) => Some(session),
+                Err(e) if e.kind() == ErrorKind::NotFound => None,
+                Err(source) => return Err(AocError::Io { path: SESSION_FILE.into(), source }),
+            },
+        };
+        let session = session.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        Ok(Site::new(&base_url, session))
+    }
+
+    /// URL of a day's puzzle page, `path` being appended, e.g. `/input`
+    pub fn day_url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, path)
+    }
+
+    /// the `Cookie` header value, or an error explaining how to log in
+    fn cookie(&self) -> Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(format!("no session token: set AOC_SESSION or save it in {}", SESSION_FILE).into()),
+        }
+    }
+
+    /// the text of a GET request, failing on any non-2xx status
+    fn get(&self, url: &str) -> Result<String> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()?).call()
+            .map_err(|e| request_error(url, e))?;
+        response.into_string().map_err(|e| format!("{}: {}", url, e).into())
+    }
+
+    /// a day's personal puzzle input
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&self.day_url(year, day, "/input"))
+    }
+
+    /// the HTML of a day's puzzle page
+    pub fn puzzle_page(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&self.day_url(year, day, ""))
+    }
+
+    /// submit an answer, returning the HTML of the page that judges it
+    pub fn post_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = self.day_url(year, day, "/answer");
+        let response = self.agent.post(&url).set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| request_error(&url, e))?;
+        response.into_string().map_err(|e| format!("{}: {}", url, e).into())
+    }
+}
+
+/// describe a failed request, including the first line of any error page
+fn request_error(url: &str, error: ureq::Error) -> AocError {
+    match error {
+        ureq::Error::Status(code, response) => {
+            let reason = response.status_text().to_string();
+            let body = response.into_string().unwrap_or_default();
+            let detail = body.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+            format!("{}: {} {} {}", url, code, reason, detail).trim_end().to_string().into()
+        },
+        e => format!("{}: {}", url, e).into(),
+    }
+}
+
+/// the first `<pre><code>` block of a puzzle page, which is the example
+/// input on every 2023 day
+pub fn example_from_page(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    let text = text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"")
+        .replace("&#39;", "'").replace("&amp;", "&");
+    Some(text)
+}
+
+/// where a fetched file came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// already on disk, so not requested again
+    Cached(PathBuf),
+}
+
+/// save a day's `example.input` and `puzzle.input` into `dir`,
+/// skipping files that are already there
+pub fn fetch(site: &Site, year: u16, day: u8, dir: &Path) -> Result<Vec<Fetched>> {
+    fs::create_dir_all(dir).map_err(|source| AocError::Io { path: dir.to_path_buf(), source })?;
+    let mut fetched = Vec::new();
+
+    let example = dir.join("example.input");
+    if example.exists() {
+        fetched.push(Fetched::Cached(example));
+    } else {
+        let page = site.puzzle_page(year, day)?;
+        let text = example_from_page(&page)
+            .ok_or_else(|| format!("no example found on {}", site.day_url(year, day, "")))?;
+        write(&example, &text)?;
+        fetched.push(Fetched::Downloaded(example));
+    }
+
+    let puzzle = dir.join("puzzle.input");
+    if puzzle.exists() {
+        fetched.push(Fetched::Cached(puzzle));
+    } else {
+        write(&puzzle, &site.input(year, day)?)?;
+        fetched.push(Fetched::Downloaded(puzzle));
+    }
+    Ok(fetched)
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).map_err(|source| AocError::Io { path: path.to_path_buf(), source })
+}
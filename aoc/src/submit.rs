@@ -0,0 +1,263 @@
+//! Submitting answers, with a local ledger of every attempt.
+//!
+//! The ledger lives in each day's directory as `submissions.toml`. Before
+//! anything is sent, it is checked so that an answer already judged wrong
+//! (or ruled out by an earlier "too high"/"too low") is never submitted
+//! again, and so that the site's waiting period after a wrong answer is
+//! respected.
+
+use crate::site::Site;
+use aoc_common::{AocError, Answer, Part, Result};
+use regex::Regex;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const LEDGER: &str = "submissions.toml";
+
+/// how the site judged a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint which way
+    Wrong,
+    /// rejected unjudged because the last answer was too recent
+    TooSoon,
+    /// the part was already solved on the site
+    AlreadySolved,
+    /// a response page we don't know how to read
+    Unrecognized,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too soon",
+            Verdict::AlreadySolved => "already solved",
+            Verdict::Unrecognized => "unrecognized",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verdict, String> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "too high" => Verdict::TooHigh,
+            "too low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "too soon" => Verdict::TooSoon,
+            "already solved" => Verdict::AlreadySolved,
+            "unrecognized" => Verdict::Unrecognized,
+            _ => return Err(format!("unknown verdict '{}'", s)),
+        })
+    }
+}
+
+/// the verdict on a submission, and how long the site wants us to wait
+/// before the next one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Judgement {
+    pub verdict: Verdict,
+    /// seconds
+    pub wait: Option<u64>,
+}
+
+/// read the judgement out of the page returned for a submission
+pub fn parse_response(html: &str) -> Judgement {
+    let verdict = if html.contains("That's the right answer") {
+        Verdict::Correct
+    } else if html.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if html.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if html.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if html.contains("Did you already complete it?") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unrecognized
+    };
+    Judgement { verdict, wait: parse_wait(html) }
+}
+
+/// "You have 1m 5s left to wait" or "Please wait 5 minutes before trying again"
+fn parse_wait(html: &str) -> Option<u64> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(caps) = left.captures(html) {
+        let minutes: u64 = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        return Some(minutes * 60 + caps[2].parse::<u64>().unwrap());
+    }
+    let penalty = Regex::new(r"(?i)please wait (one|\d+) minutes? before trying again").unwrap();
+    penalty.captures(html).map(|caps| match &caps[1] {
+        "one" => 60,
+        n => n.parse::<u64>().unwrap() * 60,
+    })
+}
+
+/// one submission, as recorded in the ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// unix time of the submission
+    pub time: u64,
+    /// unix time before which nothing more should be submitted
+    pub retry_after: Option<u64>,
+}
+
+/// every answer submitted for one day
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// load the ledger in `dir`; a missing file is an empty ledger
+    pub fn load(dir: &Path) -> Result<Ledger> {
+        let path = dir.join(LEDGER);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(AocError::Io { path, source }),
+        };
+        let attempts = parse_ledger(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Ledger { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// refuse `answer` if it is already known to be wrong, or if the site's
+    /// waiting period has not yet run out at unix time `now`
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<()> {
+        if let Some(until) = self.attempts.iter().filter_map(|a| a.retry_after).max() {
+            if until > now {
+                return Err(format!("the site asked us to wait another {}s before submitting", until - now).into());
+            }
+        }
+        let attempts = || self.attempts.iter().filter(|a| a.part == part);
+        if let Some(a) = attempts().find(|a| a.verdict == Verdict::Correct) {
+            return Err(format!("part {} is already solved: {}", part, a.answer).into());
+        }
+        if let Some(a) = attempts().find(|a| a.answer == answer && a.verdict.is_wrong()) {
+            return Err(format!("{} was already submitted and judged {}", answer, a.verdict).into());
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            let bound = |verdict| attempts()
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.parse::<i128>().ok());
+            if let Some(high) = bound(Verdict::TooHigh).filter(|&high| n >= high).min() {
+                return Err(format!("{} is too high: {} already was", n, high).into());
+            }
+            if let Some(low) = bound(Verdict::TooLow).filter(|&low| n <= low).max() {
+                return Err(format!("{} is too low: {} already was", n, low).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// append an attempt to the ledger file
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let mut entry = format!("\n[[attempt]]\npart = {}\nanswer = {}\nverdict = \"{}\"\ntime = {}\n",
+            attempt.part, toml::Value::from(attempt.answer.as_str()), attempt.verdict, attempt.time);
+        if let Some(retry_after) = attempt.retry_after {
+            entry += &format!("retry_after = {}\n", retry_after);
+        }
+        OpenOptions::new().create(true).append(true).open(&self.path)
+            .and_then(|mut file| file.write_all(entry.as_bytes()))
+            .map_err(|source| AocError::Io { path: self.path.clone(), source })?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_ledger(text: &str) -> Result<Vec<Attempt>, String> {
+    let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+    let Some(attempts) = table.get("attempt") else {
+        return Ok(Vec::new());
+    };
+    let attempts = attempts.as_array().ok_or("'attempt' should be an array of tables")?;
+    attempts.iter().enumerate().map(|(i, attempt)| {
+        let field = |key: &str| attempt.get(key)
+            .ok_or_else(|| format!("attempt {} has no '{}'", i + 1, key));
+        let time = |key: &str| field(key)?.as_integer().map(|t| t as u64)
+            .ok_or_else(|| format!("attempt {}: '{}' should be a unix time", i + 1, key));
+        Ok(Attempt {
+            part: field("part")?.to_string().parse()?,
+            answer: field("answer")?.as_str().ok_or("answer should be a string")?.to_string(),
+            verdict: field("verdict")?.as_str().ok_or("verdict should be a string")?.parse()?,
+            time: time("time")?,
+            retry_after: if attempt.get("retry_after").is_some() { Some(time("retry_after")?) } else { None },
+        })
+    }).collect()
+}
+
+/// the 24 hours after a puzzle unlocks at midnight US Eastern (UTC-5),
+/// as unix times
+pub fn release_day(year: u16, day: u8) -> Range<u64> {
+    let start = days_from_civil(year as i64, 12, day as i64) as u64 * 86400 + 5 * 3600;
+    start..start + 86400
+}
+
+/// days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// submit the answer to one part at unix time `now`, unless the ledger
+/// or the release-day rule says not to, and record the result
+pub fn submit(site: &Site, year: u16, day: u8, part: Part, answer: &Answer, ledger: &mut Ledger, now: u64)
+    -> Result<Judgement>
+{
+    if *answer == Answer::Unsolved {
+        return Err(format!("part {} has no solution to submit", part).into());
+    }
+    if release_day(year, day).contains(&now) {
+        return Err(format!("{} day {} was released today; nothing is submitted on release day", year, day).into());
+    }
+    let answer = answer.to_string();
+    ledger.check(part, &answer, now)?;
+
+    let mut judgement = parse_response(&site.post_answer(year, day, part, &answer)?);
+    judgement.wait = match judgement.verdict {
+        // the site always makes you wait after a wrong answer, even if
+        // the page is worded in a way parse_wait doesn't follow
+        v if v.is_wrong() => Some(judgement.wait.unwrap_or(60)),
+        _ => judgement.wait,
+    };
+    ledger.record(Attempt {
+        part,
+        answer,
+        verdict: judgement.verdict,
+        time: now,
+        retry_after: judgement.wait.map(|wait| now + wait),
+    })?;
+    Ok(judgement)
+}
@@ -0,0 +1,63 @@
+//! Check every day against the answers recorded in its `answers.toml`.
+
+use aoc::answers::{self, MANIFEST};
+use aoc_common::{read_input, Day};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::thread;
+
+/// check one day, returning a description of each mismatch
+fn check_day(root: &Path, day: &Day) -> Vec<String> {
+    let dir = root.join(day.dir());
+    let manifest = match answers::load(&dir) {
+        Ok(manifest) => manifest,
+        Err(e) => return vec![e.to_string()],
+    };
+    let mut failures = Vec::new();
+
+    for entry in fs::read_dir(&dir).expect("day directory is readable") {
+        let path = entry.expect("day directory is readable").path();
+        if path.extension() != Some(OsStr::new("input")) {
+            continue;
+        }
+        let name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+        if !manifest.iter().any(|answers| answers.file == name) {
+            failures.push(format!("{}: {} is not listed in {}", dir.display(), name, MANIFEST));
+        }
+    }
+
+    for answers in manifest {
+        let path = dir.join(&answers.file);
+        let text = match read_input(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                failures.push(e.to_string());
+                continue;
+            },
+        };
+        for (part, expected) in answers.parts {
+            match (day.solve)(&text, part) {
+                Ok(answer) if answer == expected => {},
+                Ok(answer) => failures.push(format!("{} part {}: expected {}, got {}",
+                    path.display(), part, expected, answer)),
+                Err(e) => failures.push(format!("{} part {}: {}", path.display(), part, e.in_file(&path))),
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_recorded_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc crate is inside the repo");
+    let failures: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = aoc::DAYS.iter()
+            .map(|day| scope.spawn(move || check_day(root, day)))
+            .collect();
+        handles.into_iter()
+            .flat_map(|handle| handle.join().expect("solver panicked"))
+            .collect()
+    });
+    assert!(failures.is_empty(), "{} wrong answers:\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,59 @@
+//! A local stand-in for the Advent of Code website.
+
+// each test binary uses a different part of the request
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// one request received by the stand-in
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// e.g. `GET /2023/day/1 HTTP/1.1`
+    pub line: String,
+    pub cookie: String,
+    pub body: String,
+}
+
+/// serve `respond`'s (status, body) for every request on a free port,
+/// returning the base URL and the requests seen so far
+pub fn serve(respond: fn(&Request) -> (&'static str, String)) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut cookie = String::new();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim();
+                if header.is_empty() {
+                    break;
+                }
+                let (name, value) = header.split_once(": ").unwrap();
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.to_string(),
+                    "content-length" => length = value.parse().unwrap(),
+                    _ => {},
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let request = Request { line: line.trim().to_string(), cookie, body: String::from_utf8(body).unwrap() };
+            let (status, body) = respond(&request);
+            seen.lock().unwrap().push(request);
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body).unwrap();
+        }
+    });
+    (base_url, requests)
+}
@@ -0,0 +1,47 @@
+//! `aoc fetch` against a local stand-in for the website.
+
+mod common;
+
+use aoc::site::{self, Fetched, Site};
+use common::Request;
+use std::fs;
+
+const PAGE: &str = "<article><p>For example:</p>\n<pre><code>1abc2\n<em>pqr3stu8vwx</em>\na1b2c3d4e5f\n</code></pre></article>";
+
+fn respond(request: &Request) -> (&'static str, String) {
+    if request.cookie != "session=secret" {
+        return ("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get yours.".into());
+    }
+    match request.line.as_str() {
+        "GET /2023/day/1 HTTP/1.1" => ("200 OK", PAGE.into()),
+        "GET /2023/day/1/input HTTP/1.1" => ("200 OK", "two1nine\n".into()),
+        _ => ("404 Not Found", "404 Not Found".into()),
+    }
+}
+
+#[test]
+fn test_fetch_downloads_once() {
+    let (base_url, requests) = common::serve(respond);
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let err = site::fetch(&Site::new(&base_url, Some("wrong".into())), 2023, 1, &dir).unwrap_err();
+    assert!(err.to_string().contains("400 Bad Request Puzzle inputs differ by user."), "{}", err);
+    requests.lock().unwrap().clear();
+
+    let site = Site::new(&base_url, Some("secret".into()));
+    let fetched = site::fetch(&site, 2023, 1, &dir).unwrap();
+    assert_eq!(fetched, vec![
+        Fetched::Downloaded(dir.join("example.input")),
+        Fetched::Downloaded(dir.join("puzzle.input")),
+    ]);
+    assert_eq!(fs::read_to_string(dir.join("example.input")).unwrap(), "1abc2\npqr3stu8vwx\na1b2c3d4e5f\n");
+    assert_eq!(fs::read_to_string(dir.join("puzzle.input")).unwrap(), "two1nine\n");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    let fetched = site::fetch(&site, 2023, 1, &dir).unwrap();
+    assert!(fetched.iter().all(|f| matches!(f, Fetched::Cached(_))));
+    assert_eq!(requests.lock().unwrap().len(), 2, "cached files were downloaded again");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,62 @@
+//! `aoc submit` against a local stand-in for the website.
+
+mod common;
+
+use aoc::submit::{self, release_day, Ledger, Verdict};
+use aoc::site::Site;
+use aoc_common::{Answer, Part};
+use common::Request;
+use std::fs;
+
+fn respond(request: &Request) -> (&'static str, String) {
+    assert_eq!(request.line, "POST /2023/day/1/answer HTTP/1.1");
+    assert_eq!(request.cookie, "session=secret");
+    let page = match request.body.as_str() {
+        "level=1&answer=42" => "<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>",
+        _ => "<article><p>That's not the right answer; your answer is too high.  \
+              Please wait one minute before trying again.</p></article>",
+    };
+    ("200 OK", page.into())
+}
+
+#[test]
+fn test_submit_consults_ledger() {
+    let (base_url, requests) = common::serve(respond);
+    let site = Site::new(&base_url, Some("secret".into()));
+    let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let mut ledger = Ledger::load(&dir).unwrap();
+    let submit = |ledger: &mut Ledger, answer: i64, now| {
+        submit::submit(&site, 2023, 1, Part::One, &Answer::from(answer), ledger, now)
+    };
+
+    let released = release_day(2023, 1).start;
+    assert_eq!(released, 1701406800, "2023-12-01 00:00 EST");
+    let err = submit(&mut ledger, 100, released + 3600).unwrap_err();
+    assert!(err.to_string().contains("release day"), "{}", err);
+
+    let now = release_day(2023, 1).end;
+    let judgement = submit(&mut ledger, 100, now).unwrap();
+    assert_eq!((judgement.verdict, judgement.wait), (Verdict::TooHigh, Some(60)));
+
+    for (answer, time, refusal) in [
+        (90, now + 10, "wait another 50s"),
+        (100, now + 100, "already submitted and judged too high"),
+        (150, now + 100, "150 is too high: 100 already was"),
+    ] {
+        let err = submit(&mut ledger, answer, time).unwrap_err();
+        assert!(err.to_string().contains(refusal), "{}", err);
+    }
+
+    assert_eq!(submit(&mut ledger, 42, now + 100).unwrap().verdict, Verdict::Correct);
+    let err = submit(&mut ledger, 42, now + 200).unwrap_err();
+    assert!(err.to_string().contains("already solved"), "{}", err);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    let reloaded = Ledger::load(&dir).unwrap();
+    assert_eq!(reloaded.attempts(), ledger.attempts());
+    assert_eq!(reloaded.attempts()[0].retry_after, Some(now + 60));
+
+    fs::remove_dir_all(&dir).unwrap();
+}